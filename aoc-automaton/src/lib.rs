@@ -0,0 +1,297 @@
+//! Cellular automata: a set of live cells that steps forward by counting each cell's live
+//! neighbours against a birth/survival rule.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use aoc_grid::{Grid, Point, ADJACENT};
+
+/// Which cells there are and which of them neighbour each other.
+///
+/// Neighbourhoods must be symmetric: if `b` is one of `a`'s neighbours, `a` is one of `b`'s.
+pub trait Topology {
+    type Cell: Copy + Debug + Eq + Hash;
+
+    fn neighbours(&self, cell: Self::Cell) -> Vec<Self::Cell>;
+
+    /// Every cell, if there are only so many. Only needed for rules that bring cells to life with
+    /// no live neighbours at all.
+    fn cells(&self) -> Option<Vec<Self::Cell>> {
+        None
+    }
+}
+
+/// The live neighbour counts at which a dead cell comes to life and a live cell stays alive.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Rule {
+    born: u128,
+    survives: u128,
+}
+
+impl Rule {
+    /// Counts above 127 never match.
+    pub const fn new(born: &[usize], survives: &[usize]) -> Self {
+        Rule { born: counts_mask(born), survives: counts_mask(survives) }
+    }
+
+    pub fn born(&self, count: usize) -> bool {
+        count < 128 && self.born >> count & 1 == 1
+    }
+
+    pub fn survives(&self, count: usize) -> bool {
+        count < 128 && self.survives >> count & 1 == 1
+    }
+}
+
+const fn counts_mask(counts: &[usize]) -> u128 {
+    let mut mask = 0;
+    let mut i = 0;
+    while i < counts.len() {
+        // Counts past the mask's width can never be reached by `born` or `survives`.
+        if counts[i] < 128 {
+            mask |= 1 << counts[i];
+        }
+        i += 1;
+    }
+    mask
+}
+
+/// The live cells of a topology, stepped forward together by a rule.
+#[derive(Clone, Debug)]
+pub struct Automaton<T: Topology> {
+    topology: T,
+    rule: Rule,
+    live: HashSet<T::Cell>,
+}
+
+impl<T: Topology> Automaton<T> {
+    pub fn new(topology: T, rule: Rule, live: impl IntoIterator<Item = T::Cell>) -> Self {
+        Automaton { topology, rule, live: live.into_iter().collect() }
+    }
+
+    pub fn topology(&self) -> &T {
+        &self.topology
+    }
+
+    pub fn live(&self) -> &HashSet<T::Cell> {
+        &self.live
+    }
+
+    pub fn is_live(&self, cell: T::Cell) -> bool {
+        self.live.contains(&cell)
+    }
+
+    /// Brings `cell` to life if it's dead, or kills it if it's alive.
+    pub fn toggle(&mut self, cell: T::Cell) {
+        if !self.live.remove(&cell) {
+            self.live.insert(cell);
+        }
+    }
+
+    /// The number of live cells.
+    pub fn count(&self) -> usize {
+        self.live.len()
+    }
+
+    /// Runs one step, returning whether any cell changed.
+    ///
+    /// Panics if the rule brings cells to life with no live neighbours but the topology can't list
+    /// its cells.
+    pub fn step(&mut self) -> bool {
+        let mut counts: HashMap<T::Cell, usize> = HashMap::new();
+        for &cell in &self.live {
+            for neighbour in self.topology.neighbours(cell) {
+                *counts.entry(neighbour).or_insert(0) += 1;
+            }
+        }
+        let count = |cell| counts.get(&cell).copied().unwrap_or(0);
+
+        let mut next: HashSet<_> = self.live.iter().copied().filter(|&cell| self.rule.survives(count(cell))).collect();
+        let candidates = if self.rule.born(0) {
+            self.topology.cells().expect("a rule with births from nothing needs a bounded topology")
+        } else {
+            counts.keys().copied().collect()
+        };
+        next.extend(candidates.into_iter().filter(|cell| !self.live.contains(cell) && self.rule.born(count(*cell))));
+
+        let changed = next != self.live;
+        self.live = next;
+        changed
+    }
+
+    /// Runs `steps` steps.
+    pub fn run(&mut self, steps: usize) {
+        for _ in 0..steps {
+            self.step();
+        }
+    }
+
+    /// Steps until a step changes nothing, returning how many steps did change something.
+    ///
+    /// Never returns if the automaton doesn't settle.
+    pub fn run_to_fixpoint(&mut self) -> usize {
+        let mut steps = 0;
+        while self.step() {
+            steps += 1;
+        }
+        steps
+    }
+}
+
+/// Which cells on a [`Square`] grid count as a cell's neighbours.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Neighbourhood {
+    /// The eight cells around it.
+    Adjacent,
+    /// The first cell seen in each of the eight directions, looking past any gaps.
+    LineOfSight,
+}
+
+/// Some of the points of a 2D grid, such as the seats in a waiting area, with the others being
+/// gaps between them.
+#[derive(Clone, Debug)]
+pub struct Square {
+    neighbours: HashMap<Point, Vec<Point>>,
+}
+
+impl Square {
+    /// The points of `grid` for which `is_cell` holds, neighbouring by `neighbourhood`.
+    pub fn new<C>(grid: &Grid<C>, is_cell: impl Fn(&C) -> bool, neighbourhood: Neighbourhood) -> Self {
+        let neighbours = grid.positions()
+            .filter(|&point| is_cell(&grid[point]))
+            .map(|point| {
+                let neighbours = match neighbourhood {
+                    Neighbourhood::Adjacent => grid.neighbours8(point)
+                        .filter(|&neighbour| is_cell(&grid[neighbour]))
+                        .collect(),
+                    Neighbourhood::LineOfSight => ADJACENT.iter()
+                        .filter_map(|&direction| grid.ray(point, direction).find(|&seen| is_cell(&grid[seen])))
+                        .collect(),
+                };
+                (point, neighbours)
+            })
+            .collect();
+        Square { neighbours }
+    }
+}
+
+impl Topology for Square {
+    type Cell = Point;
+
+    fn neighbours(&self, cell: Point) -> Vec<Point> {
+        self.neighbours.get(&cell).cloned().unwrap_or_default()
+    }
+
+    fn cells(&self) -> Option<Vec<Point>> {
+        Some(self.neighbours.keys().copied().collect())
+    }
+}
+
+/// Endless `N`-dimensional space, where each cell neighbours the `3^N - 1` cells around it.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Lattice<const N: usize>;
+
+impl<const N: usize> Topology for Lattice<N> {
+    type Cell = [i32; N];
+
+    fn neighbours(&self, cell: [i32; N]) -> Vec<[i32; N]> {
+        let mut neighbours = vec![cell];
+        for axis in 0..N {
+            neighbours = neighbours.into_iter()
+                .flat_map(|cell| (-1..=1).map(move |offset| {
+                    let mut neighbour = cell;
+                    neighbour[axis] += offset;
+                    neighbour
+                }))
+                .collect();
+        }
+        neighbours.retain(|&neighbour| neighbour != cell);
+        neighbours
+    }
+}
+
+/// An endless floor of hexagons in doubled coordinates, where east is `(2, 0)` and north-east is
+/// `(1, 1)`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Hex;
+
+impl Hex {
+    /// Offsets to the six neighbours of a hexagon, clockwise from east.
+    pub const ADJACENT: [(i32, i32); 6] = [
+        (2, 0),
+        (1, -1),
+        (-1, -1),
+        (-2, 0),
+        (-1, 1),
+        (1, 1),
+    ];
+}
+
+impl Topology for Hex {
+    type Cell = (i32, i32);
+
+    fn neighbours(&self, (x, y): (i32, i32)) -> Vec<(i32, i32)> {
+        Hex::ADJACENT.iter().map(|(dx, dy)| (x + dx, y + dy)).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Conway's Game of Life.
+    const LIFE: Rule = Rule::new(&[3], &[2, 3]);
+
+    #[test]
+    fn test_rule() {
+        assert!(LIFE.born(3) && !LIFE.born(2));
+        assert!(LIFE.survives(2) && LIFE.survives(3) && !LIFE.survives(4));
+        assert!(!LIFE.born(200));
+
+        const WIDE: Rule = Rule::new(&[128, 3], &[200]);
+        assert_eq!(LIFE.born, WIDE.born);
+        assert!(!WIDE.born(0) && !WIDE.born(128) && !WIDE.survives(0) && !WIDE.survives(200));
+        assert_eq!(Rule::new(&[], &[]), Rule::new(&[usize::MAX], &[128]));
+    }
+
+    #[test]
+    fn test_life() {
+        let mut blinker = Automaton::new(Lattice::<2>, LIFE, vec![[0, -1], [0, 0], [0, 1]]);
+        assert!(blinker.step());
+        let mut live: Vec<_> = blinker.live().iter().copied().collect();
+        live.sort();
+        assert_eq!(vec![[-1, 0], [0, 0], [1, 0]], live);
+        blinker.run(3);
+        assert!(blinker.is_live([0, 1]));
+
+        let mut block = Automaton::new(Lattice::<2>, LIFE, vec![[0, 0], [0, 1], [1, 0], [1, 1]]);
+        assert_eq!(0, block.run_to_fixpoint());
+        assert_eq!(4, block.count());
+    }
+
+    #[test]
+    fn test_neighbours() {
+        assert_eq!(2, Lattice::<1>.neighbours([5]).len());
+        assert_eq!(80, Lattice::<4>.neighbours([0; 4]).len());
+        assert!(Hex.neighbours((0, 0)).iter().all(|&neighbour| Hex.neighbours(neighbour).contains(&(0, 0))));
+
+        let grid = Grid::<bool>::parse("#.#\n...\n#.#").unwrap();
+        let adjacent = Square::new(&grid, |&cell| cell, Neighbourhood::Adjacent);
+        assert_eq!(Vec::<Point>::new(), adjacent.neighbours((0, 0)));
+        let mut seen = Square::new(&grid, |&cell| cell, Neighbourhood::LineOfSight).neighbours((0, 0));
+        seen.sort();
+        assert_eq!(vec![(0, 2), (2, 0), (2, 2)], seen);
+    }
+
+    #[test]
+    fn test_births_from_nothing() {
+        let grid = Grid::<bool>::parse("##\n##").unwrap();
+        let square = Square::new(&grid, |&cell| cell, Neighbourhood::Adjacent);
+        let mut automaton = Automaton::new(square, Rule::new(&[0], &[]), None);
+        assert!(automaton.step());
+        assert_eq!(4, automaton.count());
+        automaton.step();
+        assert_eq!(0, automaton.count());
+    }
+}
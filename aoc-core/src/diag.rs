@@ -0,0 +1,28 @@
+//! Diagnostics that solvers can print while they work, shown on stderr only when asked for.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+
+/// How chatty solvers should be: 0 is silent, 1 is a summary, 2 and up is everything.
+pub fn verbosity() -> u8 {
+    VERBOSITY.load(Ordering::Relaxed)
+}
+
+pub fn set_verbosity(level: u8) {
+    VERBOSITY.store(level, Ordering::Relaxed);
+}
+
+/// Prints to stderr like `eprintln!`, but only at or above the given verbosity level.
+///
+/// ```
+/// aoc_core::diag!(1, "found {} sea monsters", 2);
+/// ```
+#[macro_export]
+macro_rules! diag {
+    ($level:expr, $($arg:tt)*) => {
+        if $crate::diag::verbosity() >= $level {
+            eprintln!($($arg)*);
+        }
+    };
+}
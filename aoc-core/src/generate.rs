@@ -0,0 +1,56 @@
+//! Made-up puzzle inputs, for stress testing the solvers on more than the one real input.
+//!
+//! Each day crate has a `generate` module behind its `generate` feature that builds these.
+
+use crate::{solve, Part, Solver};
+
+/// A generated puzzle input, with the answers to it wherever the generator can work them out
+/// without solving the puzzle the way the solver does.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Generated {
+    pub input: String,
+    pub answers: [Option<String>; 2],
+}
+
+impl Generated {
+    pub fn new(input: String, part1: Option<String>, part2: Option<String>) -> Self {
+        Generated { input, answers: [part1, part2] }
+    }
+
+    pub fn answer(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.answers[0].as_deref(),
+            Part::Two => self.answers[1].as_deref(),
+        }
+    }
+
+    /// The answers in the format of a day's `answers` file, with a blank line for each unknown.
+    pub fn answers_file(&self) -> String {
+        let line = |answer: &Option<String>| answer.clone().unwrap_or_default();
+        format!("{}\n{}\n", line(&self.answers[0]), line(&self.answers[1]))
+    }
+
+    /// Solves the input with `S` and panics unless it parses and gets every known answer.
+    ///
+    /// Parts without a known answer are skipped, as some take far longer than the rest.
+    pub fn assert_solves<S: Solver>(&self) {
+        let parts: Vec<Part> = Part::BOTH.iter().copied().filter(|&part| self.answer(part).is_some()).collect();
+        let answers = solve::<S>(&self.input, &parts)
+            .unwrap_or_else(|e| panic!("{}\n{}", e.render("<generated>", &self.input), self.input));
+        for answer in answers {
+            assert_eq!(self.answer(answer.part), Some(&*answer.value), "part {} of\n{}", answer.part, self.input);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_answers_file() {
+        let generated = Generated::new("1\n2\n".to_owned(), None, Some("3".to_owned()));
+        assert_eq!("\n3\n", generated.answers_file());
+        assert_eq!(Some("3"), generated.answer(Part::Two));
+    }
+}
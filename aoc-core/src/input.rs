@@ -0,0 +1,129 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Cursor, Read};
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+
+/// Where a puzzle input comes from.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+    Inline(String),
+}
+
+impl Source {
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            Source::File(path) => fs::read_to_string(path)
+                .map_err(|error| InputError { source: self.clone(), error }),
+            Source::Stdin => {
+                let mut buf = String::new();
+                io::stdin().read_to_string(&mut buf)
+                    .map_err(|error| InputError { source: self.clone(), error })?;
+                Ok(buf)
+            }
+            Source::Inline(input) => Ok(input.clone()),
+        }
+    }
+
+    /// Opens the input to be read a bit at a time, rather than all at once as
+    /// [`read`](Source::read) does.
+    pub fn open(&self) -> Result<Box<dyn BufRead>, InputError> {
+        match self {
+            Source::File(path) => match fs::File::open(path) {
+                Ok(file) => Ok(Box::new(BufReader::new(file))),
+                Err(error) => Err(InputError { source: self.clone(), error }),
+            },
+            Source::Stdin => Ok(Box::new(io::stdin().lock())),
+            Source::Inline(input) => Ok(Box::new(Cursor::new(input.clone().into_bytes()))),
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::File(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::Inline(_) => write!(f, "<inline>"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub source: Source,
+    pub error: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "could not read {}: {}", self.source, self.error)
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Command line flags for choosing a puzzle input.
+#[derive(Args, Clone, Debug, Default)]
+pub struct InputArgs {
+    /// Read the puzzle input from this file (`-` for stdin)
+    #[arg(long, short, value_name = "PATH", group = "source")]
+    pub input: Option<PathBuf>,
+    /// Read the puzzle input from stdin
+    #[arg(long, group = "source")]
+    pub stdin: bool,
+    /// Use this text as the puzzle input
+    #[arg(long, value_name = "TEXT", group = "source")]
+    pub inline: Option<String>,
+}
+
+impl InputArgs {
+    /// The source asked for on the command line, if any.
+    pub fn source(&self) -> Option<Source> {
+        if let Some(text) = &self.inline {
+            Some(Source::Inline(text.clone()))
+        } else if self.stdin || self.input.as_deref() == Some(Path::new("-")) {
+            Some(Source::Stdin)
+        } else {
+            self.input.clone().map(Source::File)
+        }
+    }
+}
+
+/// The input file at `path`, or `baked_in` when there is no such file.
+pub fn file_or_default(path: &Path, baked_in: Option<&str>) -> Source {
+    match (baked_in, path.exists()) {
+        (Some(input), false) => Source::Inline(input.to_owned()),
+        _ => Source::File(path.to_owned()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_source() {
+        let args = InputArgs { inline: Some("0,3,6".to_owned()), ..InputArgs::default() };
+        assert_eq!("0,3,6", args.source().unwrap().read().unwrap());
+        assert_eq!(vec!["0,3,6".to_owned()], args.source().unwrap().open().unwrap().lines().collect::<Result<Vec<_>, _>>().unwrap());
+
+        let args = InputArgs { input: Some(PathBuf::from("-")), ..InputArgs::default() };
+        assert_eq!(Some(Source::Stdin), args.source());
+
+        assert_eq!(None, InputArgs::default().source());
+    }
+
+    #[test]
+    fn test_file_or_default() {
+        let missing = Path::new("no/such/input");
+        let source = file_or_default(missing, Some("389125467"));
+        assert_eq!("389125467", source.read().unwrap());
+
+        let error = file_or_default(missing, None).read().unwrap_err();
+        assert_eq!(Source::File(missing.to_owned()), error.source);
+    }
+}
@@ -0,0 +1,261 @@
+use std::fmt::{self, Display};
+use std::hint::black_box;
+use std::path::Path;
+use std::process;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use clap::Parser;
+
+pub mod diag;
+pub mod generate;
+pub mod input;
+pub mod output;
+pub mod parse;
+pub mod repl;
+
+use input::{InputArgs, Source};
+use output::{Format, OutputArgs};
+pub use parse::ParseError;
+
+/// One of the two halves of a day's puzzle.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    /// The requested part, or both of them if none was given.
+    pub fn selected(part: Option<Part>) -> &'static [Part] {
+        match part {
+            Some(Part::One) => &Part::BOTH[..1],
+            Some(Part::Two) => &Part::BOTH[1..],
+            None => &Part::BOTH,
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("part must be 1 or 2, not `{}`", s)),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// A day's puzzle: how to read its input and how to answer both parts.
+pub trait Solver {
+    const DAY: u8;
+
+    /// Puzzle input baked into the solver, used when there is no input file.
+    const DEFAULT_INPUT: Option<&'static str> = None;
+
+    type Input<'a>;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+    fn part1(input: &Self::Input<'_>) -> Self::Answer1;
+    fn part2(input: &Self::Input<'_>) -> Self::Answer2;
+}
+
+/// An answer to one part of a puzzle.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Answer {
+    pub day: u8,
+    pub part: Part,
+    pub value: String,
+    /// Time spent on this part, not counting parsing the input.
+    pub elapsed: Duration,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Day {} part {}: {}", self.day, self.part, self.value)
+    }
+}
+
+/// How long one run of a day took, stage by stage.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub parts: Vec<(Part, Duration)>,
+}
+
+/// Object-safe view of a [`Solver`], so every day can sit in the same list.
+pub trait DynSolver {
+    fn day(&self) -> u8;
+    fn default_input(&self) -> Option<&'static str>;
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<Vec<Answer>, ParseError>;
+    fn time(&self, input: &str, parts: &[Part]) -> Result<Timings, ParseError>;
+}
+
+impl<S: Solver> DynSolver for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn default_input(&self) -> Option<&'static str> {
+        S::DEFAULT_INPUT
+    }
+
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<Vec<Answer>, ParseError> {
+        solve::<S>(input, parts)
+    }
+
+    fn time(&self, input: &str, parts: &[Part]) -> Result<Timings, ParseError> {
+        time::<S>(input, parts)
+    }
+}
+
+/// Parses `input` once and answers each of `parts` from it.
+pub fn solve<S: Solver>(input: &str, parts: &[Part]) -> Result<Vec<Answer>, ParseError> {
+    let input = S::parse(input)?;
+    Ok(parts.iter().map(|&part| {
+        let start = Instant::now();
+        let value = match part {
+            Part::One => S::part1(&input).to_string(),
+            Part::Two => S::part2(&input).to_string(),
+        };
+        Answer { day: S::DAY, part, value, elapsed: start.elapsed() }
+    }).collect())
+}
+
+/// Like [`solve`], but times parsing and each part separately instead of keeping the answers.
+pub fn time<S: Solver>(input: &str, parts: &[Part]) -> Result<Timings, ParseError> {
+    let start = Instant::now();
+    let input = black_box(S::parse(input)?);
+    let parse = start.elapsed();
+
+    let parts = parts.iter().map(|&part| {
+        let start = Instant::now();
+        match part {
+            Part::One => drop(black_box(S::part1(&input))),
+            Part::Two => drop(black_box(S::part2(&input))),
+        }
+        (part, start.elapsed())
+    }).collect();
+    Ok(Timings { parse, parts })
+}
+
+/// Solve one day of Advent of Code 2020
+///
+/// Days whose binaries take flags of their own can flatten these into theirs.
+#[derive(Parser)]
+pub struct DayArgs {
+    /// Only solve this part (1 or 2)
+    #[arg(long)]
+    pub part: Option<Part>,
+    #[command(flatten)]
+    pub input: InputArgs,
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+impl DayArgs {
+    /// Reads the input asked for, exiting if it can't be read.
+    ///
+    /// Without any input flags this reads `input` from the working directory, or the solver's
+    /// baked-in input if there is no such file.
+    pub fn read<S: Solver>(&self) -> (Source, String) {
+        let source = self.input.source()
+            .unwrap_or_else(|| input::file_or_default(Path::new("input"), S::DEFAULT_INPUT));
+        let buf = source.read().unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            process::exit(1);
+        });
+        (source, buf)
+    }
+
+    /// Solves the selected parts and prints their answers, exiting if the input can't be read
+    /// or parsed.
+    pub fn solve<S: Solver>(&self) {
+        let (source, buf) = self.read::<S>();
+        match solve::<S>(&buf, Part::selected(self.part)) {
+            Ok(answers) => {
+                for answer in answers {
+                    match self.output.format {
+                        Format::Text => println!("{}", answer.value),
+                        Format::Json => println!("{}", answer.to_json()),
+                    }
+                }
+            }
+            Err(e) => {
+                eprint!("{}", e.render(&source.to_string(), &buf));
+                process::exit(1);
+            }
+        }
+    }
+}
+
+/// Entry point shared by the `dayN` binaries.
+pub fn main<S: Solver>() {
+    let args = DayArgs::parse();
+    args.output.apply();
+    args.solve::<S>();
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Example;
+
+    impl Solver for Example {
+        const DAY: u8 = 0;
+
+        type Input<'a> = Vec<&'a str>;
+        type Answer1 = usize;
+        type Answer2 = String;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+            Ok(input.lines().collect())
+        }
+
+        fn part1(lines: &Self::Input<'_>) -> usize {
+            lines.len()
+        }
+
+        fn part2(lines: &Self::Input<'_>) -> String {
+            lines.concat()
+        }
+    }
+
+    #[test]
+    fn test_part() {
+        assert_eq!(Ok(Part::Two), "2".parse());
+        assert!("3".parse::<Part>().is_err());
+        assert_eq!(&[Part::One], Part::selected(Some(Part::One)));
+        assert_eq!(&Part::BOTH, Part::selected(None));
+    }
+
+    #[test]
+    fn test_solve() {
+        let answers = Example.solve("a\nb\nc", &Part::BOTH).unwrap();
+        assert_eq!("3", answers[0].value);
+        assert_eq!("abc", answers[1].value);
+        assert_eq!("Day 0 part 2: abc", answers[1].to_string());
+    }
+
+    #[test]
+    fn test_time() {
+        let timings = Example.time("a\nb", &[Part::Two]).unwrap();
+        assert_eq!(vec![Part::Two], timings.parts.iter().map(|(part, _)| *part).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,72 @@
+use clap::{Args, ValueEnum};
+use serde::Serialize;
+
+use crate::{diag, Answer, Part};
+
+/// How results are written to stdout.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum Format {
+    /// Plain text for people
+    #[default]
+    Text,
+    /// One JSON object per line, with times in nanoseconds
+    Json,
+}
+
+/// Command line flags for choosing what gets printed.
+#[derive(Args, Clone, Debug, Default)]
+pub struct OutputArgs {
+    /// Format of the results on stdout
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+    /// Print diagnostics to stderr (repeat for more)
+    #[arg(long, short, action = clap::ArgAction::Count)]
+    pub verbose: u8,
+}
+
+impl OutputArgs {
+    /// Sets the diagnostics level for the rest of the run.
+    pub fn apply(&self) {
+        diag::set_verbosity(self.verbose);
+    }
+}
+
+#[derive(Serialize)]
+struct Record<'a> {
+    day: u8,
+    part: u8,
+    answer: &'a str,
+    elapsed: u64,
+}
+
+impl Answer {
+    /// The answer as a single line of JSON, e.g.
+    /// `{"day":1,"part":2,"answer":"241861950","elapsed":1520}`.
+    pub fn to_json(&self) -> String {
+        let record = Record {
+            day: self.day,
+            part: match self.part { Part::One => 1, Part::Two => 2 },
+            answer: &self.value,
+            elapsed: self.elapsed.as_nanos() as u64,
+        };
+        serde_json::to_string(&record).expect("answers always serialize")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_to_json() {
+        let answer = Answer {
+            day: 21,
+            part: Part::Two,
+            value: "mxmxvkd,sqjhc,fvjkl".to_owned(),
+            elapsed: Duration::from_micros(3),
+        };
+        assert_eq!(r#"{"day":21,"part":2,"answer":"mxmxvkd,sqjhc,fvjkl","elapsed":3000}"#, answer.to_json());
+    }
+}
@@ -0,0 +1,153 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// A problem with a puzzle input, pointing at the offending token.
+///
+/// Lines and columns count from 1. Parsers for a single line leave `line` at 1 and let the
+/// caller say which line it was with [`ParseError::on_line`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(column: usize, expected: impl Into<String>, found: impl Into<String>) -> Self {
+        ParseError {
+            line: 1,
+            column,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    /// An error about `token`, which must be a slice of `line`.
+    pub fn at(line: &str, token: &str, expected: impl Into<String>) -> Self {
+        ParseError::new(column(line, token), expected, token)
+    }
+
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    /// Moves the error down by `lines`, for errors found inside a block of a larger input.
+    pub fn shift_lines(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+
+    /// Moves the error right by `columns`, for errors found inside part of a line.
+    pub fn shift_columns(mut self, columns: usize) -> Self {
+        self.column += columns;
+        self
+    }
+
+    /// Formats the error with the offending line of `input` quoted beneath it.
+    pub fn render(&self, origin: &str, input: &str) -> String {
+        let mut rendered = format!("error: {}\n", self);
+        let text = self.line.checked_sub(1).and_then(|index| input.lines().nth(index));
+        let gutter = " ".repeat(self.line.to_string().len());
+
+        rendered.push_str(&format!("{}--> {}:{}:{}\n", gutter, origin, self.line, self.column));
+        if let Some(text) = text {
+            let width = self.found.chars().count().max(1);
+            rendered.push_str(&format!("{} |\n", gutter));
+            rendered.push_str(&format!("{} | {}\n", self.line, text));
+            rendered.push_str(&format!(
+                "{} | {}{}\n",
+                gutter,
+                " ".repeat(self.column.saturating_sub(1)),
+                "^".repeat(width),
+            ));
+        }
+        rendered
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}: expected {}, ", self.line, self.column, self.expected)?;
+        if self.found.is_empty() {
+            write!(f, "found end of line")
+        } else {
+            write!(f, "found `{}`", self.found)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The 1-based column at which `token` starts, where `token` is a slice of `line`.
+pub fn column(line: &str, token: &str) -> usize {
+    let start = line.as_ptr() as usize;
+    let offset = (token.as_ptr() as usize).wrapping_sub(start);
+    match line.get(..offset) {
+        Some(before) if offset <= line.len() => before.chars().count() + 1,
+        _ => 1,
+    }
+}
+
+/// Parses `token`, a slice of `line`, as a number.
+pub fn number<T: FromStr>(line: &str, token: &str) -> Result<T, ParseError> {
+    token.parse().map_err(|_| ParseError::at(line, token, "a number"))
+}
+
+/// Parses each line of `input`, tagging any error with the line it came from.
+pub fn lines<'a, T, F>(input: &'a str, mut parse_line: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&'a str) -> Result<T, ParseError>,
+{
+    input.lines()
+        .enumerate()
+        .map(|(index, line)| parse_line(line).map_err(|e| e.on_line(index + 1)))
+        .collect()
+}
+
+/// Splits `input` into blank-line separated blocks, each with the number of lines before it.
+pub fn blocks(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input.split("\n\n").map(move |block| {
+        let offset = block.as_ptr() as usize - input.as_ptr() as usize;
+        (input[..offset].matches('\n').count(), block)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_column() {
+        let line = "mem[8] = 11";
+        assert_eq!(10, column(line, &line[9..]));
+        assert_eq!(1, column(line, &String::from("elsewhere")));
+    }
+
+    #[test]
+    fn test_lines() {
+        let error = lines("1\n2\nx3\n", |line| number::<i64>(line, line)).unwrap_err();
+        assert_eq!(ParseError { line: 3, column: 1, expected: "a number".into(), found: "x3".into() }, error);
+    }
+
+    #[test]
+    fn test_blocks() {
+        let blocks: Vec<_> = blocks("a\nb\n\nc\n\nd").collect();
+        assert_eq!(vec![(0, "a\nb"), (3, "c"), (5, "d")], blocks);
+    }
+
+    #[test]
+    fn test_render() {
+        let input = "nop +0\nfoo +1\n";
+        let line = input.lines().nth(1).unwrap();
+        let error = ParseError::at(line, &line[..3], "`acc`, `jmp` or `nop`").on_line(2);
+        assert_eq!("\
+error: line 2, column 1: expected `acc`, `jmp` or `nop`, found `foo`
+ --> input:2:1
+  |
+2 | foo +1
+  | ^^^
+", error.render("input", input));
+    }
+}
@@ -0,0 +1,122 @@
+//! Poking at a puzzle's state one command at a time, for `aoc repl`.
+//!
+//! Each day crate that supports it has a `repl` module behind its `repl` feature, with a
+//! [`Session`] over the parsed input.
+
+use std::io::{self, BufRead, Write};
+
+use crate::{DynSolver, ParseError, Solver};
+
+/// A command a session understands.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Command {
+    pub name: &'static str,
+    /// What follows the name, for `help`, e.g. `[n]`.
+    pub args: &'static str,
+    pub help: &'static str,
+}
+
+/// A puzzle's state, along with commands for looking at it and moving it along.
+pub trait Session {
+    fn commands(&self) -> &'static [Command];
+
+    /// Runs one of [`commands`](Session::commands) with the rest of its line, returning what to
+    /// print.
+    fn run(&mut self, command: &str, args: &str) -> Result<String, String>;
+}
+
+/// A day whose puzzle can be explored from the REPL.
+pub trait Explore: Solver {
+    fn explore<'a>(input: Self::Input<'a>) -> Box<dyn Session + 'a>;
+}
+
+/// Object-safe view of an [`Explore`], so every day that has one can sit in the same list.
+pub trait DynExplore: DynSolver {
+    fn explore<'a>(&self, input: &'a str) -> Result<Box<dyn Session + 'a>, ParseError>;
+}
+
+impl<E: Explore> DynExplore for E {
+    fn explore<'a>(&self, input: &'a str) -> Result<Box<dyn Session + 'a>, ParseError> {
+        Ok(E::explore(E::parse(input)?))
+    }
+}
+
+/// Reads commands from `input` until it runs out or says `quit`, writing `prompt` before each one
+/// and what it printed or went wrong after.
+pub fn run(session: &mut dyn Session, prompt: &str, input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+    write!(output, "{}", prompt)?;
+    output.flush()?;
+    for line in input.lines() {
+        let line = line?;
+        let (command, args) = line.trim().split_once(' ').unwrap_or((line.trim(), ""));
+        let args = args.trim();
+
+        match command {
+            "" => {}
+            "quit" | "exit" => return Ok(()),
+            "help" => write!(output, "{}", help(session.commands()))?,
+            _ if session.commands().iter().any(|c| c.name == command) => match session.run(command, args) {
+                Ok(printed) if printed.is_empty() => {}
+                Ok(printed) => writeln!(output, "{}", printed.trim_end())?,
+                Err(e) => writeln!(output, "error: {}", e)?,
+            },
+            _ => writeln!(output, "unknown command `{}`; try `help`", command)?,
+        }
+        write!(output, "{}", prompt)?;
+        output.flush()?;
+    }
+    writeln!(output)
+}
+
+fn help(commands: &[Command]) -> String {
+    let builtins = [
+        Command { name: "help", args: "", help: "List the commands" },
+        Command { name: "quit", args: "", help: "Leave the REPL" },
+    ];
+    let usage = |c: &Command| if c.args.is_empty() { c.name.to_owned() } else { format!("{} {}", c.name, c.args) };
+    let width = commands.iter().chain(&builtins).map(|c| usage(c).len()).max().unwrap_or(0);
+    commands.iter()
+        .chain(&builtins)
+        .map(|c| format!("  {:width$}  {}\n", usage(c), c.help, width = width))
+        .collect()
+}
+
+/// Reads an optional repeat count, such as the `5` in `step 5`, which is 1 if left out.
+pub fn count(args: &str) -> Result<usize, String> {
+    if args.is_empty() {
+        Ok(1)
+    } else {
+        args.parse().map_err(|_| format!("expected a number of times, not `{}`", args))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// A counter that can be added to.
+    struct Counter(i64);
+
+    impl Session for Counter {
+        fn commands(&self) -> &'static [Command] {
+            &[Command { name: "add", args: "[n]", help: "Add n, or 1" }]
+        }
+
+        fn run(&mut self, _command: &str, args: &str) -> Result<String, String> {
+            self.0 += count(args)? as i64;
+            Ok(self.0.to_string())
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let commands = "add\n\nadd 5\nadd x\nsub 1\nhelp\nquit\nadd\n";
+        let mut output = Vec::new();
+        run(&mut Counter(0), "> ", commands.as_bytes(), &mut output).unwrap();
+        assert_eq!(
+            "> 1\n> > 6\n> error: expected a number of times, not `x`\n> unknown command `sub`; try `help`\n\
+             >   add [n]  Add n, or 1\n  help     List the commands\n  quit     Leave the REPL\n> ",
+            String::from_utf8(output).unwrap(),
+        );
+    }
+}
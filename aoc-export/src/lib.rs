@@ -0,0 +1,274 @@
+//! Pictures of puzzles as they're solved, written out as PPM, PGM or PBM images or as SVG.
+//!
+//! Days draw each step onto a [`Scene`] and hand it to [`Frames`], which writes either every
+//! frame into a directory, for stitching into an animation, or just the last one to a file.
+
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use aoc_core::{DynSolver, ParseError, Part, Solver};
+use aoc_grid::Grid;
+
+pub mod raster;
+pub mod svg;
+
+/// A colour, as red, green and blue.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+
+    /// How bright the colour looks, from 0 to 255.
+    pub fn luma(self) -> u8 {
+        let Rgb(r, g, b) = self;
+        ((299 * r as u32 + 587 * g as u32 + 114 * b as u32) / 1000) as u8
+    }
+}
+
+impl fmt::Display for Rgb {
+    /// As `#rrggbb`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// Something drawn on a scene, in pixels from the top left corner.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Shape {
+    Rect { x: f64, y: f64, width: f64, height: f64, colour: Rgb },
+    Polygon { points: Vec<(f64, f64)>, colour: Rgb },
+}
+
+/// A picture as a list of shapes over a background, drawn in order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Scene {
+    width: usize,
+    height: usize,
+    background: Rgb,
+    shapes: Vec<Shape>,
+}
+
+impl Scene {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+        Scene { width, height, background, shapes: Vec::new() }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn background(&self) -> Rgb {
+        self.background
+    }
+
+    pub fn shapes(&self) -> &[Shape] {
+        &self.shapes
+    }
+
+    pub fn rect(&mut self, (x, y): (f64, f64), (width, height): (f64, f64), colour: Rgb) {
+        self.shapes.push(Shape::Rect { x, y, width, height, colour });
+    }
+
+    pub fn polygon(&mut self, points: Vec<(f64, f64)>, colour: Rgb) {
+        self.shapes.push(Shape::Polygon { points, colour });
+    }
+
+    /// Draws each cell of `grid` as a square `size` pixels across, with the grid's top left
+    /// corner at `origin`. Cells that `colour` gives no colour are left as they are.
+    pub fn grid<T>(&mut self, grid: &Grid<T>, origin: (f64, f64), size: f64, colour: impl Fn(&T) -> Option<Rgb>) {
+        for point @ (x, y) in grid.positions() {
+            if let Some(colour) = colour(&grid[point]) {
+                let corner = (origin.0 + x as f64 * size, origin.1 + y as f64 * size);
+                self.rect(corner, (size, size), colour);
+            }
+        }
+    }
+}
+
+/// A file format for a scene.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+    /// Full colour binary Netpbm
+    Ppm,
+    /// Greyscale binary Netpbm
+    Pgm,
+    /// Black and white binary Netpbm, with anything darker than mid-grey black
+    Pbm,
+    Svg,
+}
+
+impl Format {
+    pub const ALL: [Format; 4] = [Format::Ppm, Format::Pgm, Format::Pbm, Format::Svg];
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Ppm => "ppm",
+            Format::Pgm => "pgm",
+            Format::Pbm => "pbm",
+            Format::Svg => "svg",
+        }
+    }
+
+    /// The format a file name's extension asks for, if any.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        path.extension()?.to_str()?.parse().ok()
+    }
+
+    pub fn write(self, scene: &Scene, out: &mut impl Write) -> io::Result<()> {
+        match self {
+            Format::Ppm => raster::write_ppm(&raster::rasterize(scene), out),
+            Format::Pgm => raster::write_pgm(&raster::rasterize(scene), out),
+            Format::Pbm => raster::write_pbm(&raster::rasterize(scene), out),
+            Format::Svg => out.write_all(svg::render(scene).as_bytes()),
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Format::ALL.iter()
+            .copied()
+            .find(|format| format.extension() == s.to_ascii_lowercase())
+            .ok_or_else(|| format!("format must be ppm, pgm, pbm or svg, not `{}`", s))
+    }
+}
+
+/// Where the frames of a run go: each one numbered in a directory, or only the last to a file.
+#[derive(Debug)]
+pub struct Frames {
+    path: PathBuf,
+    format: Format,
+    sequence: bool,
+    count: usize,
+    last: Option<Scene>,
+}
+
+impl Frames {
+    /// Keeps only the last frame, to write to `path` on [`finish`](Frames::finish).
+    pub fn file(path: impl Into<PathBuf>, format: Format) -> Self {
+        Frames { path: path.into(), format, sequence: false, count: 0, last: None }
+    }
+
+    /// Writes every frame to `dir` as it comes, as `frame-0000.ppm`, `frame-0001.ppm` and so on,
+    /// making the directory if need be.
+    pub fn sequence(dir: impl Into<PathBuf>, format: Format) -> io::Result<Self> {
+        let path = dir.into();
+        fs::create_dir_all(&path)?;
+        Ok(Frames { path, format, sequence: true, count: 0, last: None })
+    }
+
+    /// The number of frames pushed so far.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn push(&mut self, scene: Scene) -> io::Result<()> {
+        if self.sequence {
+            let name = format!("frame-{:04}.{}", self.count, self.format.extension());
+            write_file(&self.path.join(name), self.format, &scene)?;
+        } else {
+            self.last = Some(scene);
+        }
+        self.count += 1;
+        Ok(())
+    }
+
+    /// Writes out the last frame if there's only the one file, returning the number of frames.
+    pub fn finish(self) -> io::Result<usize> {
+        if let Some(scene) = &self.last {
+            write_file(&self.path, self.format, scene)?;
+        }
+        Ok(self.count)
+    }
+}
+
+fn write_file(path: &Path, format: Format, scene: &Scene) -> io::Result<()> {
+    let mut out = BufWriter::new(File::create(path)?);
+    format.write(scene, &mut out)?;
+    out.flush()
+}
+
+/// A day that can draw its puzzle as it solves it.
+pub trait Export: Solver {
+    /// Pushes a frame for each step of solving `part`, or a single frame for days whose answer
+    /// is one picture.
+    fn export(input: &Self::Input<'_>, part: Part, frames: &mut Frames) -> io::Result<()>;
+}
+
+/// Why a day's frames couldn't be exported.
+#[derive(Debug)]
+pub enum Error {
+    Parse(ParseError),
+    Io(io::Error),
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+/// Object-safe view of an [`Export`], so every day that has one can sit in the same list.
+pub trait DynExport: DynSolver {
+    fn export(&self, input: &str, part: Part, frames: &mut Frames) -> Result<(), Error>;
+}
+
+impl<E: Export> DynExport for E {
+    fn export(&self, input: &str, part: Part, frames: &mut Frames) -> Result<(), Error> {
+        let input = E::parse(input)?;
+        E::export(&input, part, frames)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        assert_eq!(Ok(Format::Pbm), "PBM".parse());
+        assert!("png".parse::<Format>().is_err());
+        assert_eq!(Some(Format::Svg), Format::from_path(Path::new("out/floor.svg")));
+        assert_eq!(None, Format::from_path(Path::new("frames")));
+        assert_eq!("#0a80ff", Rgb(10, 128, 255).to_string());
+    }
+
+    #[test]
+    fn test_frames() {
+        let dir = std::env::temp_dir().join(format!("aoc-export-test-{}", std::process::id()));
+        let scene = Scene::new(2, 1, Rgb::WHITE);
+
+        let mut frames = Frames::sequence(&dir, Format::Pgm).unwrap();
+        frames.push(scene.clone()).unwrap();
+        frames.push(scene.clone()).unwrap();
+        assert_eq!(2, frames.finish().unwrap());
+        assert_eq!(b"P5\n2 1\n255\n\xff\xff", &fs::read(dir.join("frame-0001.pgm")).unwrap()[..]);
+
+        let path = dir.join("last.pbm");
+        let mut frames = Frames::file(&path, Format::Pbm);
+        frames.push(Scene::new(3, 1, Rgb::BLACK)).unwrap();
+        frames.push(scene).unwrap();
+        assert_eq!(2, frames.finish().unwrap());
+        assert_eq!(b"P4\n2 1\n\x00", &fs::read(&path).unwrap()[..]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,133 @@
+//! Scenes as grids of pixels, and the binary Netpbm formats for writing them.
+
+use std::io::{self, Write};
+
+use aoc_grid::Grid;
+
+use crate::{Rgb, Scene, Shape};
+
+/// Colours each pixel by the last shape covering its centre.
+pub fn rasterize(scene: &Scene) -> Grid<Rgb> {
+    let mut image = Grid::new(scene.width(), scene.height(), scene.background());
+    for shape in scene.shapes() {
+        let ((left, top), (right, bottom), colour) = match shape {
+            Shape::Rect { x, y, width, height, colour } => ((*x, *y), (x + width, y + height), *colour),
+            Shape::Polygon { points, colour } => {
+                let (top_left, bottom_right) = bounds(points);
+                (top_left, bottom_right, *colour)
+            }
+        };
+
+        // Only the pixels whose centres fall in the shape's bounding box.
+        let clamp = |v: f64, max: usize| (v - 0.5).ceil().clamp(0.0, max as f64) as usize;
+        for y in clamp(top, image.height())..clamp(bottom, image.height()) {
+            for x in clamp(left, image.width())..clamp(right, image.width()) {
+                let covered = match shape {
+                    Shape::Rect { .. } => true,
+                    Shape::Polygon { points, .. } => contains(points, (x as f64 + 0.5, y as f64 + 0.5)),
+                };
+                if covered {
+                    image[(x, y)] = colour;
+                }
+            }
+        }
+    }
+    image
+}
+
+/// The top left and bottom right corners of the smallest rectangle around `points`.
+fn bounds(points: &[(f64, f64)]) -> ((f64, f64), (f64, f64)) {
+    let mut top_left = (f64::INFINITY, f64::INFINITY);
+    let mut bottom_right = (f64::NEG_INFINITY, f64::NEG_INFINITY);
+    for &(x, y) in points {
+        top_left = (top_left.0.min(x), top_left.1.min(y));
+        bottom_right = (bottom_right.0.max(x), bottom_right.1.max(y));
+    }
+    (top_left, bottom_right)
+}
+
+/// Whether `point` is inside the polygon, by counting how many of its edges a ray to the right
+/// crosses.
+fn contains(polygon: &[(f64, f64)], (x, y): (f64, f64)) -> bool {
+    let mut inside = false;
+    for (i, &(x1, y1)) in polygon.iter().enumerate() {
+        let (x2, y2) = polygon[(i + 1) % polygon.len()];
+        if (y1 > y) != (y2 > y) && x < x1 + (y - y1) / (y2 - y1) * (x2 - x1) {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+/// Writes a binary PPM (`P6`).
+pub fn write_ppm(image: &Grid<Rgb>, out: &mut impl Write) -> io::Result<()> {
+    write!(out, "P6\n{} {}\n255\n", image.width(), image.height())?;
+    let pixels: Vec<u8> = image.iter().flat_map(|&Rgb(r, g, b)| [r, g, b]).collect();
+    out.write_all(&pixels)
+}
+
+/// Writes a binary PGM (`P5`) of each pixel's [`luma`](Rgb::luma).
+pub fn write_pgm(image: &Grid<Rgb>, out: &mut impl Write) -> io::Result<()> {
+    write!(out, "P5\n{} {}\n255\n", image.width(), image.height())?;
+    let pixels: Vec<u8> = image.iter().map(|colour| colour.luma()).collect();
+    out.write_all(&pixels)
+}
+
+/// Writes a binary PBM (`P4`), where pixels darker than mid-grey are black.
+pub fn write_pbm(image: &Grid<Rgb>, out: &mut impl Write) -> io::Result<()> {
+    write!(out, "P4\n{} {}\n", image.width(), image.height())?;
+    // Each row is padded out to a whole number of bytes, most significant bit first.
+    for row in image.rows() {
+        let bytes: Vec<u8> = row.chunks(8)
+            .map(|pixels| pixels.iter().enumerate().fold(0, |byte, (i, colour)| {
+                if colour.luma() < 128 { byte | 0x80 >> i } else { byte }
+            }))
+            .collect();
+        out.write_all(&bytes)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rasterize() {
+        let mut scene = Scene::new(4, 4, Rgb::WHITE);
+        scene.rect((1.0, 1.0), (2.0, 1.0), Rgb::BLACK);
+        scene.polygon(vec![(0.0, 2.0), (4.0, 2.0), (0.0, 4.0)], Rgb(255, 0, 0));
+        let image = raster_chars(&rasterize(&scene));
+        assert_eq!("....\n.##.\nrrr.\nr...\n", image);
+
+        // Shapes hanging off the edge are cut off rather than wrapping round.
+        let mut scene = Scene::new(2, 2, Rgb::WHITE);
+        scene.rect((-1.0, 1.0), (2.0, 5.0), Rgb::BLACK);
+        assert_eq!("..\n#.\n", raster_chars(&rasterize(&scene)));
+    }
+
+    fn raster_chars(image: &Grid<Rgb>) -> String {
+        image.render(|&colour| match colour {
+            Rgb::WHITE => '.',
+            Rgb::BLACK => '#',
+            _ => 'r',
+        })
+    }
+
+    #[test]
+    fn test_netpbm() {
+        let image = Grid::from_cells(9, vec![Rgb::BLACK, Rgb(255, 0, 0), Rgb::WHITE, Rgb::WHITE, Rgb::WHITE, Rgb::WHITE, Rgb::WHITE, Rgb::WHITE, Rgb::BLACK]);
+
+        let mut ppm = Vec::new();
+        write_ppm(&image, &mut ppm).unwrap();
+        assert_eq!(b"P6\n9 1\n255\n\0\0\0\xff\0\0", &ppm[..17]);
+
+        let mut pgm = Vec::new();
+        write_pgm(&image, &mut pgm).unwrap();
+        assert_eq!(b"P5\n9 1\n255\n\0\x4c\xff", &pgm[..14]);
+
+        let mut pbm = Vec::new();
+        write_pbm(&image, &mut pbm).unwrap();
+        assert_eq!(b"P4\n9 1\n\xc0\x80", &pbm[..]);
+    }
+}
@@ -0,0 +1,47 @@
+//! Scenes as SVG, which keeps shapes such as hexagons crisp at any size.
+
+use std::fmt::Write;
+
+use crate::{Scene, Shape};
+
+pub fn render(scene: &Scene) -> String {
+    let (width, height) = (scene.width(), scene.height());
+    let mut svg = String::new();
+    writeln!(svg, r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}">"#, width, height).unwrap();
+    writeln!(svg, r#"<rect width="100%" height="100%" fill="{}"/>"#, scene.background()).unwrap();
+    for shape in scene.shapes() {
+        match shape {
+            Shape::Rect { x, y, width, height, colour } => {
+                writeln!(svg, r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#, x, y, width, height, colour).unwrap();
+            }
+            Shape::Polygon { points, colour } => {
+                let points: Vec<String> = points.iter().map(|(x, y)| format!("{:.2},{:.2}", x, y)).collect();
+                writeln!(svg, r#"<polygon points="{}" fill="{}"/>"#, points.join(" "), colour).unwrap();
+            }
+        }
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Rgb;
+
+    #[test]
+    fn test_render() {
+        let mut scene = Scene::new(3, 2, Rgb::WHITE);
+        scene.rect((1.0, 0.0), (2.0, 2.0), Rgb::BLACK);
+        scene.polygon(vec![(0.0, 0.0), (1.5, 0.0), (0.0, 2.0)], Rgb(255, 0, 0));
+        assert_eq!(
+            r##"<svg xmlns="http://www.w3.org/2000/svg" width="3" height="2" viewBox="0 0 3 2">
+<rect width="100%" height="100%" fill="#ffffff"/>
+<rect x="1" y="0" width="2" height="2" fill="#000000"/>
+<polygon points="0.00,0.00 1.50,0.00 0.00,2.00" fill="#ff0000"/>
+</svg>
+"##,
+            render(&scene),
+        );
+    }
+}
@@ -0,0 +1,333 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::slice;
+
+use aoc_core::ParseError;
+
+/// A position in a grid, as `(x, y)` with `y` counting rows down from the top.
+pub type Point = (usize, usize);
+
+/// Offsets to the four orthogonal neighbours, clockwise from up.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets to all eight neighbours, clockwise from up.
+pub const ADJACENT: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A grid cell that is written as a single character in puzzle inputs.
+pub trait Cell: Sized {
+    /// The characters `from_char` accepts, for error messages.
+    const EXPECTED: &'static str;
+
+    fn from_char(c: char) -> Option<Self>;
+    fn to_char(&self) -> char;
+}
+
+impl Cell for bool {
+    const EXPECTED: &'static str = "`#` or `.`";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        if *self { '#' } else { '.' }
+    }
+}
+
+/// A rectangular grid stored row by row.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+        Grid { width, height, cells }
+    }
+
+    /// A grid `width` cells wide holding `cells` row by row.
+    ///
+    /// Panics if `cells` does not fill a whole number of rows.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        assert_eq!(width * height, cells.len(), "{} cells do not make rows of {}", cells.len(), width);
+        Grid { width, height, cells }
+    }
+
+    /// Parses a character map, one row per line, turning each character into a cell with `cell`.
+    ///
+    /// Every row must be as wide as the first. `expected` describes the characters `cell` accepts.
+    pub fn parse_with(
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for (index, line) in input.lines().enumerate() {
+            let len = line.chars().count();
+            let width = *width.get_or_insert(len);
+            if len != width {
+                let expected = format!("a row of {} cells", width);
+                return Err(ParseError::at(line, line, expected).on_line(index + 1));
+            }
+
+            for (i, c) in line.char_indices() {
+                let found = &line[i..i + c.len_utf8()];
+                cells.push(cell(c).ok_or_else(|| ParseError::at(line, found, expected).on_line(index + 1))?);
+            }
+            height += 1;
+        }
+
+        Ok(Grid { width: width.unwrap_or(0), height, cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Point) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        if self.contains(point) {
+            Some(&self.cells[point.1 * self.width + point.0])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.contains(point) {
+            Some(&mut self.cells[point.1 * self.width + point.0])
+        } else {
+            None
+        }
+    }
+
+    /// The cell at `(x, y)` on a grid that repeats forever in every direction.
+    pub fn get_wrapping(&self, (x, y): (isize, isize)) -> &T {
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+        &self[(x, y)]
+    }
+
+    /// The point `offset` away from `point`, if it is still on the grid.
+    pub fn offset(&self, (x, y): Point, (dx, dy): (isize, isize)) -> Option<Point> {
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+        Some((x, y)).filter(|&point| self.contains(point))
+    }
+
+    /// The orthogonal neighbours of `point` that are on the grid.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        ORTHOGONAL.iter().filter_map(move |&offset| self.offset(point, offset))
+    }
+
+    /// All neighbours of `point`, diagonals included, that are on the grid.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        ADJACENT.iter().filter_map(move |&offset| self.offset(point, offset))
+    }
+
+    /// The points seen looking from `point` in `direction`, up to the edge of the grid.
+    pub fn ray(&self, point: Point, direction: (isize, isize)) -> impl Iterator<Item = Point> + '_ {
+        let mut current = Some(point);
+        std::iter::from_fn(move || {
+            current = current.and_then(|point| self.offset(point, direction));
+            current
+        })
+    }
+
+    /// Every point on the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Draws the grid one row per line, using `f` to pick each cell's character.
+    pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String {
+        let mut rendered = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            rendered.extend(row.iter().map(&mut f));
+            rendered.push('\n');
+        }
+        rendered
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    /// Turns the grid a quarter turn clockwise.
+    pub fn rotate(&self) -> Self {
+        let height = self.height;
+        Grid::from_fn(self.height, self.width, |(x, y)| self[(y, height - 1 - x)].clone())
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        let width = self.width;
+        Grid::from_fn(self.width, self.height, |(x, y)| self[(width - 1 - x, y)].clone())
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        let height = self.height;
+        Grid::from_fn(self.width, self.height, |(x, y)| self[(x, height - 1 - y)].clone())
+    }
+
+    /// The four rotations of the grid followed by the four rotations of its mirror image.
+    pub fn orientations(&self) -> impl Iterator<Item = Self> {
+        let mut orientations = Vec::with_capacity(8);
+        for start in [self.clone(), self.flip_horizontal()] {
+            let mut grid = start;
+            for _ in 0..4 {
+                let next = grid.rotate();
+                orientations.push(grid);
+                grid = next;
+            }
+        }
+        orientations.into_iter()
+    }
+}
+
+impl<T: Cell> Grid<T> {
+    /// Parses a character map of [`Cell`]s.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Grid::parse_with(input, T::EXPECTED, T::from_char)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        assert!(self.contains(point), "{:?} is outside a {}x{} grid", point, self.width, self.height);
+        &self.cells[point.1 * self.width + point.0]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        assert!(self.contains(point), "{:?} is outside a {}x{} grid", point, self.width, self.height);
+        &mut self.cells[point.1 * self.width + point.0]
+    }
+}
+
+impl<T: Cell> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.render(Cell::to_char))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "\
+#..
+.#.
+..#
+.##";
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::<bool>::parse(INPUT).unwrap();
+        assert_eq!((3, 4), (grid.width(), grid.height()));
+        assert!(grid[(1, 1)]);
+        assert!(!grid[(1, 0)]);
+        assert_eq!(INPUT, grid.to_string().trim_end());
+
+        let error = Grid::<bool>::parse("#..\n.o.").unwrap_err();
+        assert_eq!(ParseError::new(2, "`#` or `.`", "o").on_line(2), error);
+
+        let error = Grid::<bool>::parse("#..\n.#").unwrap_err();
+        assert_eq!(ParseError::new(1, "a row of 3 cells", ".#").on_line(2), error);
+    }
+
+    #[test]
+    fn test_indexing() {
+        let grid = Grid::<bool>::parse(INPUT).unwrap();
+        assert_eq!(None, grid.get((3, 0)));
+        assert_eq!(Some(&true), grid.get((2, 3)));
+        assert!(*grid.get_wrapping((3, 0)));
+        assert!(*grid.get_wrapping((-1, -2)));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, ());
+        assert_eq!(vec![(1, 0), (0, 1)], grid.neighbours4((0, 0)).collect::<Vec<_>>());
+        assert_eq!(3, grid.neighbours8((2, 2)).count());
+        assert_eq!(8, grid.neighbours8((1, 1)).count());
+        assert_eq!(vec![(1, 1), (2, 2)], grid.ray((0, 0), (1, 1)).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_transforms() {
+        let grid = Grid::<bool>::parse("##.\n...").unwrap();
+        assert_eq!(".#\n.#\n..\n", grid.rotate().to_string());
+        assert_eq!(".##\n...\n", grid.flip_horizontal().to_string());
+        assert_eq!("...\n##.\n", grid.flip_vertical().to_string());
+        assert_eq!("#.\n#.\n..\n", grid.transpose().to_string());
+        assert_eq!(grid, grid.rotate().rotate().rotate().rotate());
+
+        let orientations: Vec<_> = grid.orientations().collect();
+        assert_eq!(8, orientations.len());
+        assert!(orientations.contains(&grid.transpose()));
+        assert!(orientations.contains(&grid.flip_vertical()));
+    }
+}
@@ -0,0 +1,105 @@
+//! The same functions over arbitrary-precision integers, for moduli that outgrow an `i128`.
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{One, Zero};
+
+/// The extended Euclidean algorithm: `(g, x, y)` with `g = gcd(a, b) = a*x + b*y` and `g >= 0`.
+pub fn egcd(a: &BigInt, b: &BigInt) -> (BigInt, BigInt, BigInt) {
+    let gcd = a.extended_gcd(b);
+    (gcd.gcd, gcd.x, gcd.y)
+}
+
+/// The inverse of `x` modulo `n`, if they are coprime.
+pub fn mod_inv(x: &BigInt, n: &BigInt) -> Option<BigInt> {
+    let (g, x, _) = egcd(&x.mod_floor(n), n);
+    if g.is_one() {
+        Some(x.mod_floor(n))
+    } else {
+        None
+    }
+}
+
+/// `base` to the power of `exp`, modulo `m`. Panics if `exp` is negative.
+pub fn mod_pow(base: &BigInt, exp: &BigInt, m: &BigInt) -> BigInt {
+    base.mod_floor(m).modpow(exp, m)
+}
+
+/// The smallest non-negative `x` with `x ≡ residues[i] (mod moduli[i])` for every `i`.
+///
+/// The moduli must be pairwise coprime; `None` if they aren't.
+pub fn chinese_remainder(residues: &[BigInt], moduli: &[BigInt]) -> Option<BigInt> {
+    let product: BigInt = moduli.iter().product();
+
+    let mut sum = BigInt::zero();
+    for (residue, modulus) in residues.iter().zip(moduli) {
+        let p = &product / modulus;
+        sum += residue * mod_inv(&p, modulus)? * p;
+    }
+    Some(sum.mod_floor(&product))
+}
+
+/// Solves `x ≡ residue (mod modulus)` for every `(residue, modulus)` pair, where the moduli
+/// needn't be coprime.
+///
+/// Returns the smallest non-negative `x` along with the moduli's lcm; `None` if the congruences
+/// contradict each other.
+pub fn generalized_crt(congruences: &[(BigInt, BigInt)]) -> Option<(BigInt, BigInt)> {
+    congruences.iter().try_fold((BigInt::zero(), BigInt::one()), |(a1, m1), (a2, m2)| {
+        let (g, inverse, _) = egcd(&m1, m2);
+        let difference = (a2 - &a1).mod_floor(m2);
+        if !difference.is_multiple_of(&g) {
+            return None;
+        }
+
+        let lcm = &m1 / &g * m2;
+        let k = (difference / &g * inverse).mod_floor(&(m2 / &g));
+        Some(((a1 + m1 * k).mod_floor(&lcm), lcm))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn big(n: i128) -> BigInt {
+        BigInt::from(n)
+    }
+
+    fn bigs(ns: &[i128]) -> Vec<BigInt> {
+        ns.iter().copied().map(big).collect()
+    }
+
+    #[test]
+    fn test_egcd() {
+        assert_eq!((big(2), big(-9), big(47)), egcd(&big(240), &big(46)));
+        assert_eq!(Some(big(4)), mod_inv(&big(-2), &big(9)));
+        assert_eq!(None, mod_inv(&big(6), &big(9)));
+    }
+
+    #[test]
+    fn test_mod_pow() {
+        let m = big(i128::MAX - 2);
+        let expected = crate::mod_pow(-12345, 67890, i128::MAX - 2);
+        assert_eq!(big(expected), mod_pow(&big(-12345), &big(67890), &m));
+    }
+
+    #[test]
+    fn test_chinese_remainder() {
+        assert_eq!(Some(big(23)), chinese_remainder(&bigs(&[2, 3, 2]), &bigs(&[3, 5, 7])));
+        assert_eq!(None, chinese_remainder(&bigs(&[1, 2]), &bigs(&[4, 6])));
+
+        // Past what fits in an `i128`.
+        let moduli = [big(1) << 64, (big(1) << 64) + 1, (big(1) << 64) - 1];
+        let x = (big(1) << 150) + 12345;
+        let residues: Vec<_> = moduli.iter().map(|m| &x % m).collect();
+        assert_eq!(Some(x), chinese_remainder(&residues, &moduli));
+    }
+
+    #[test]
+    fn test_generalized_crt() {
+        let congruences = [(big(2), big(4)), (big(4), big(6))];
+        assert_eq!(Some((big(10), big(12))), generalized_crt(&congruences));
+        assert_eq!(None, generalized_crt(&[(big(1), big(4)), (big(2), big(6))]));
+    }
+}
@@ -0,0 +1,192 @@
+//! Number theory for the puzzles that need it, on `i128`s that never overflow along the way.
+//!
+//! [`big`] has the same functions over arbitrary-precision integers.
+
+use std::collections::HashMap;
+
+pub mod big;
+
+/// The extended Euclidean algorithm: `(g, x, y)` with `g = gcd(a, b) = a*x + b*y` and `g >= 0`.
+pub fn egcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The inverse of `x` modulo `n`, if they are coprime.
+pub fn mod_inv(x: i128, n: i128) -> Option<i128> {
+    let (g, x, _) = egcd(x.rem_euclid(n), n);
+    if g == 1 {
+        Some(x.rem_euclid(n))
+    } else {
+        None
+    }
+}
+
+/// `a * b` modulo `m`, even where the product itself would overflow.
+pub fn mul_mod(a: i128, b: i128, m: i128) -> i128 {
+    let (a, b) = (a.rem_euclid(m), b.rem_euclid(m));
+    if let Some(product) = a.checked_mul(b) {
+        return product % m;
+    }
+
+    // Both are below `m`, so doubling either still fits in a `u128`.
+    let (mut a, mut b, m) = (a as u128, b as u128, m as u128);
+    let mut product = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            product = (product + a) % m;
+        }
+        a = (a << 1) % m;
+        b >>= 1;
+    }
+    product as i128
+}
+
+/// `base` to the power of `exp`, modulo `m`.
+pub fn mod_pow(base: i128, mut exp: u128, m: i128) -> i128 {
+    let mut base = base.rem_euclid(m);
+    let mut power = 1 % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            power = mul_mod(power, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    power
+}
+
+/// `a + b` modulo `m`, for `a` and `b` already below `m`.
+fn add_mod(a: i128, b: i128, m: i128) -> i128 {
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+/// The smallest non-negative `x` with `x ≡ residues[i] (mod moduli[i])` for every `i`.
+///
+/// The moduli must be pairwise coprime, and their product must fit in an `i128`; `None` if not.
+pub fn chinese_remainder(residues: &[i128], moduli: &[i128]) -> Option<i128> {
+    let product = moduli.iter().try_fold(1i128, |product, &modulus| product.checked_mul(modulus))?;
+
+    let mut sum = 0;
+    for (&residue, &modulus) in residues.iter().zip(moduli) {
+        let p = product / modulus;
+        let term = mul_mod(mul_mod(residue, mod_inv(p, modulus)?, modulus), p, product);
+        sum = add_mod(sum, term, product);
+    }
+    Some(sum)
+}
+
+/// Solves `x ≡ residue (mod modulus)` for every `(residue, modulus)` pair, where the moduli
+/// needn't be coprime.
+///
+/// Returns the smallest non-negative `x` along with the moduli's lcm, which every other solution
+/// differs from it by a multiple of. `None` if the congruences contradict each other or the lcm
+/// doesn't fit in an `i128`.
+pub fn generalized_crt(congruences: &[(i128, i128)]) -> Option<(i128, i128)> {
+    congruences.iter().try_fold((0i128, 1i128), |(a1, m1), &(a2, m2)| {
+        let (g, inverse, _) = egcd(m1, m2);
+        let difference = (a2.rem_euclid(m2) - a1.rem_euclid(m2)).rem_euclid(m2);
+        if difference % g != 0 {
+            return None;
+        }
+
+        let lcm = (m1 / g).checked_mul(m2)?;
+        let k = mul_mod(difference / g, inverse, m2 / g);
+        Some((add_mod(a1.rem_euclid(lcm), m1 * k, lcm), lcm))
+    })
+}
+
+/// The smallest non-negative `e` with `base^e ≡ target (mod modulus)`, by baby-step giant-step.
+///
+/// `base` must be coprime to `modulus`, or there's no answer. Takes time and memory in proportion
+/// to the square root of `modulus`.
+pub fn discrete_log(base: i128, target: i128, modulus: i128) -> Option<i128> {
+    let steps = modulus.isqrt() + 1;
+
+    let mut baby_steps = HashMap::new();
+    let mut power = 1 % modulus;
+    for j in 0..steps {
+        baby_steps.entry(power).or_insert(j);
+        power = mul_mod(power, base, modulus);
+    }
+
+    let giant_step = mod_inv(mod_pow(base, steps as u128, modulus), modulus)?;
+    let mut remaining = target.rem_euclid(modulus);
+    for i in 0..steps {
+        if let Some(&j) = baby_steps.get(&remaining) {
+            return Some(i * steps + j);
+        }
+        remaining = mul_mod(remaining, giant_step, modulus);
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_egcd() {
+        assert_eq!((2, -9, 47), egcd(240, 46));
+        assert_eq!((6, 0, -1), egcd(0, -6));
+        assert_eq!(None, mod_inv(6, 9));
+        assert_eq!(Some(4), mod_inv(-2, 9));
+    }
+
+    #[test]
+    fn test_mul_mod() {
+        let m = i128::MAX - 2;
+        assert_eq!(4, mul_mod(m - 2, m - 2, m));
+        assert_eq!(m - 6, mul_mod(m - 2, 3, m));
+        assert_eq!(6, mul_mod(-2, -3, 7));
+    }
+
+    #[test]
+    fn test_mod_pow() {
+        assert_eq!(5764801, mod_pow(7, 8, 20201227));
+        assert_eq!(1, mod_pow(3, 1_000_000_006, 1_000_000_007));
+        assert_eq!(0, mod_pow(3, 0, 1));
+        let m = (1 << 100) + 277;
+        assert_eq!(mul_mod(mod_pow(5, 99, m), 5, m), mod_pow(5, 100, m));
+    }
+
+    #[test]
+    fn test_chinese_remainder() {
+        assert_eq!(Some(23), chinese_remainder(&[2, 3, 2], &[3, 5, 7]));
+        assert_eq!(Some(1068781), chinese_remainder(&[0, 12, 55, 25, 12], &[7, 13, 59, 31, 19]));
+        assert_eq!(None, chinese_remainder(&[1, 2], &[4, 6]));
+        assert_eq!(None, chinese_remainder(&[0, 0], &[1 << 64, (1 << 64) + 1]));
+    }
+
+    #[test]
+    fn test_generalized_crt() {
+        assert_eq!(Some((10, 12)), generalized_crt(&[(2, 4), (4, 6)]));
+        assert_eq!(None, generalized_crt(&[(1, 4), (2, 6)]));
+        assert_eq!(Some((0, 1)), generalized_crt(&[]));
+        assert_eq!(Some((23, 105)), generalized_crt(&[(2, 3), (3, 5), (2, 7)]));
+    }
+
+    #[test]
+    fn test_discrete_log() {
+        assert_eq!(Some(8), discrete_log(7, 5764801, 20201227));
+        assert_eq!(Some(11), discrete_log(7, 17807724, 20201227));
+        assert_eq!(Some(0), discrete_log(3, 1, 7));
+        assert_eq!(None, discrete_log(2, 3, 7));
+    }
+}
@@ -0,0 +1,305 @@
+use std::str::FromStr;
+
+use aoc_core::ParseError;
+use aoc_grid::{Cell, Grid};
+use nom::{
+    branch::alt,
+    bytes::complete::take_while1,
+    character::complete::{char, digit1, line_ending, multispace0, one_of, space0, space1},
+    combinator::{eof, map_res, not, opt, recognize},
+    error::{ContextError, ErrorKind, FromExternalError},
+    multi::many1,
+    sequence::{pair, separated_pair, terminated, tuple},
+    Err,
+};
+
+pub use nom::error::context;
+
+/// The result of the parsers in this crate.
+pub type IResult<'a, O> = nom::IResult<&'a str, O, Error<'a>>;
+
+/// A nom error that remembers what the parser was looking for.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Error<'a> {
+    /// The input left when the parser gave up.
+    pub input: &'a str,
+    pub expected: String,
+}
+
+impl<'a> Error<'a> {
+    pub fn new(input: &'a str, expected: impl Into<String>) -> Self {
+        Error { input, expected: expected.into() }
+    }
+
+    /// Places the error in `input`, which the failed parse started from.
+    pub fn locate(&self, input: &str) -> ParseError {
+        let offset = (self.input.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| *offset <= input.len())
+            .unwrap_or(0);
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let column = before.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+
+        ParseError {
+            line,
+            column,
+            expected: self.expected.clone(),
+            found: token(self.input).to_owned(),
+        }
+    }
+}
+
+/// The word or symbol at the start of `input`, for error messages.
+fn token(input: &str) -> &str {
+    match input.chars().next() {
+        Some(c) if c.is_alphanumeric() => {
+            let end = input.find(|c: char| !c.is_alphanumeric()).unwrap_or(input.len());
+            &input[..end]
+        }
+        Some(c) if c != '\n' && c != '\r' => &input[..c.len_utf8()],
+        _ => "",
+    }
+}
+
+fn describe(kind: ErrorKind) -> String {
+    match kind {
+        ErrorKind::Digit => "a number".to_owned(),
+        ErrorKind::Alpha => "a letter".to_owned(),
+        ErrorKind::AlphaNumeric => "a letter or digit".to_owned(),
+        ErrorKind::HexDigit => "a hex digit".to_owned(),
+        ErrorKind::Space | ErrorKind::MultiSpace => "whitespace".to_owned(),
+        ErrorKind::CrLf => "a new line".to_owned(),
+        ErrorKind::Eof => "end of input".to_owned(),
+        kind => kind.description().to_lowercase(),
+    }
+}
+
+impl<'a> nom::error::ParseError<&'a str> for Error<'a> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        Error::new(input, describe(kind))
+    }
+
+    fn append(_input: &'a str, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    fn from_char(input: &'a str, c: char) -> Self {
+        Error::new(input, format!("`{}`", c))
+    }
+
+    /// Keeps whichever error got further, listing both expectations when they tie.
+    fn or(self, other: Self) -> Self {
+        match self.input.len().cmp(&other.input.len()) {
+            std::cmp::Ordering::Less => self,
+            std::cmp::Ordering::Greater => other,
+            std::cmp::Ordering::Equal => {
+                Error::new(other.input, format!("{} or {}", self.expected, other.expected))
+            }
+        }
+    }
+}
+
+impl<'a> ContextError<&'a str> for Error<'a> {
+    /// Describes errors at the very start of the context with `context`; deeper errors say more.
+    fn add_context(input: &'a str, context: &'static str, other: Self) -> Self {
+        if input.len() == other.input.len() {
+            Error::new(input, context)
+        } else {
+            other
+        }
+    }
+}
+
+impl<'a, E> FromExternalError<&'a str, E> for Error<'a> {
+    fn from_external_error(input: &'a str, kind: ErrorKind, _error: E) -> Self {
+        nom::error::ParseError::from_error_kind(input, kind)
+    }
+}
+
+/// Runs `parser` over the whole of `input`, allowing trailing whitespace.
+pub fn finish<'a, O>(input: &'a str, mut parser: impl FnMut(&'a str) -> IResult<'a, O>) -> Result<O, ParseError> {
+    match terminated(&mut parser, pair(multispace0, context("end of input", eof)))(input) {
+        Ok((_, output)) => Ok(output),
+        Err(Err::Error(e)) | Err(Err::Failure(e)) => Err(e.locate(input)),
+        Err(Err::Incomplete(_)) => Err(Error::new(&input[input.len()..], "more input").locate(input)),
+    }
+}
+
+/// Matches `literal` exactly.
+pub fn tag<'a>(literal: &'static str) -> impl FnMut(&'a str) -> IResult<'a, &'a str> {
+    move |i: &'a str| match i.strip_prefix(literal) {
+        Some(rest) => Ok((rest, &i[..literal.len()])),
+        None => Err(Err::Error(Error::new(i, format!("`{}`", literal)))),
+    }
+}
+
+/// An unsigned integer.
+pub fn unsigned<'a, T: FromStr>(i: &'a str) -> IResult<'a, T> {
+    context("a number", map_res(digit1, str::parse))(i)
+}
+
+/// An integer with an optional `+` or `-` sign.
+pub fn signed<'a, T: FromStr>(i: &'a str) -> IResult<'a, T> {
+    context("a number", map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse))(i)
+}
+
+/// `first`, then as many more as follow `separator`.
+///
+/// Unlike nom's `separated_list1`, once a separator has matched the next item must too, so the
+/// error points at the bad item rather than at everything after it.
+fn separated<'a, O, S>(
+    mut separator: impl FnMut(&'a str) -> IResult<'a, S>,
+    mut item: impl FnMut(&'a str) -> IResult<'a, O>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<O>> {
+    move |i: &'a str| {
+        let (mut i, first) = item(i)?;
+        let mut items = vec![first];
+        loop {
+            match separator(i) {
+                Ok((rest, _)) => {
+                    let (rest, next) = item(rest)?;
+                    items.push(next);
+                    i = rest;
+                }
+                Err(Err::Error(_)) => return Ok((i, items)),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+/// The end of a line that is followed by more of the same block.
+fn line_break(i: &str) -> IResult<'_, &str> {
+    terminated(line_ending, not(pair(space0, alt((line_ending, eof)))))(i)
+}
+
+/// A line with nothing but whitespace on it, and the line break before it.
+pub fn blank_line(i: &str) -> IResult<'_, &str> {
+    context("a blank line", recognize(tuple((line_ending, space0, line_ending))))(i)
+}
+
+/// Items separated by commas, e.g. `7,13,x,59`.
+pub fn comma_list<'a, O>(item: impl FnMut(&'a str) -> IResult<'a, O>) -> impl FnMut(&'a str) -> IResult<'a, Vec<O>> {
+    separated(pair(char(','), space0), item)
+}
+
+/// One item per line, stopping at a blank line or the end of the input.
+pub fn lines<'a, O>(line: impl FnMut(&'a str) -> IResult<'a, O>) -> impl FnMut(&'a str) -> IResult<'a, Vec<O>> {
+    separated(line_break, line)
+}
+
+/// Blocks separated by blank lines.
+pub fn blocks<'a, O>(block: impl FnMut(&'a str) -> IResult<'a, O>) -> impl FnMut(&'a str) -> IResult<'a, Vec<O>> {
+    separated(terminated(blank_line, not(pair(multispace0, eof))), block)
+}
+
+/// `key:value` fields separated by spaces or single line breaks, e.g. a day 4 passport.
+pub fn record<'a, K, V>(
+    key: impl FnMut(&'a str) -> IResult<'a, K>,
+    value: impl FnMut(&'a str) -> IResult<'a, V>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<(K, V)>> {
+    separated(alt((space1, line_break)), separated_pair(key, char(':'), value))
+}
+
+/// The text of a `key:value` field value: everything up to the next whitespace.
+pub fn field(i: &str) -> IResult<'_, &str> {
+    context("a value", take_while1(|c: char| !c.is_whitespace()))(i)
+}
+
+/// A `header` line, e.g. `Player 1:`, followed by `body` on the lines below it.
+pub fn section<'a, O>(
+    header: &'static str,
+    body: impl FnMut(&'a str) -> IResult<'a, O>,
+) -> impl FnMut(&'a str) -> IResult<'a, O> {
+    let mut header = terminated(tag(header), line_ending);
+    let mut body = body;
+    move |i: &'a str| {
+        let (i, _) = header(i)?;
+        body(i)
+    }
+}
+
+/// A character map of [`Cell`]s, one row per line, every row as wide as the first.
+pub fn grid<'a, T: Cell>(i: &'a str) -> IResult<'a, Grid<T>> {
+    let cell = |i: &'a str| match i.chars().next().and_then(|c| T::from_char(c).map(|cell| (c, cell))) {
+        Some((c, cell)) => Ok((&i[c.len_utf8()..], cell)),
+        None => Err(Err::Error(Error::new(i, T::EXPECTED))),
+    };
+    let (rest, rows) = lines(many1(cell))(i)?;
+
+    let width = rows[0].len();
+    let mut cells = Vec::with_capacity(width * rows.len());
+    for (row, line) in rows.into_iter().zip(i.lines()) {
+        if row.len() != width {
+            return Err(Err::Error(match line.find(|c| T::from_char(c).is_none()) {
+                Some(bad) => Error::new(&line[bad..], T::EXPECTED),
+                None => Error::new(line, format!("a row of {} cells", width)),
+            }));
+        }
+        cells.extend(row);
+    }
+    Ok((rest, Grid::from_cells(width, cells)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(Ok(("", 42u32)), unsigned("42"));
+        assert_eq!(Ok((",", -7i64)), signed("-7,"));
+        assert_eq!(Ok(12), finish("+12\n", signed::<i32>));
+
+        let error = finish("1,2,three", comma_list(unsigned::<u8>)).unwrap_err();
+        assert_eq!(ParseError::new(5, "a number", "three"), error);
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "1\n2\n\n3\n\n4\n";
+        assert_eq!(Ok(vec![vec![1, 2], vec![3], vec![4]]), finish(input, blocks(lines(unsigned::<u8>))));
+
+        let error = finish("1\n2\n\n3\nx", blocks(lines(unsigned::<u8>))).unwrap_err();
+        assert_eq!(ParseError::new(1, "a number", "x").on_line(5), error);
+    }
+
+    #[test]
+    fn test_record() {
+        let input = "ecl:gry pid:860033327\nhcl:#fffffd\n\nbyr:1937";
+        let key = || take_while1(|c: char| c.is_ascii_lowercase());
+        let records = finish(input, blocks(record(key(), field))).unwrap();
+        assert_eq!(vec![("ecl", "gry"), ("pid", "860033327"), ("hcl", "#fffffd")], records[0]);
+        assert_eq!(vec![("byr", "1937")], records[1]);
+
+        let error = finish("ecl:gry pid", record(key(), field)).unwrap_err();
+        assert_eq!(ParseError::new(12, "`:`", ""), error);
+    }
+
+    #[test]
+    fn test_section() {
+        let input = "Player 1:\n9\n2\n\nPlayer 2:\n5\n8";
+        let decks = finish(input, separated_pair(
+            section("Player 1:", lines(unsigned::<u8>)),
+            blank_line,
+            section("Player 2:", lines(unsigned::<u8>)),
+        ));
+        assert_eq!(Ok((vec![9, 2], vec![5, 8])), decks);
+
+        let error = finish("Player 3:\n1", section("Player 1:", lines(unsigned::<u8>))).unwrap_err();
+        assert_eq!(ParseError::new(1, "`Player 1:`", "Player"), error);
+    }
+
+    #[test]
+    fn test_grid() {
+        let parsed: Grid<bool> = finish("#.\n.#\n", grid).unwrap();
+        assert_eq!("#.\n.#\n", parsed.to_string());
+
+        let error = finish("#.\n.", grid::<bool>).unwrap_err();
+        assert_eq!(ParseError::new(1, "a row of 2 cells", ".").on_line(2), error);
+
+        let error = finish("#.\n.o", grid::<bool>).unwrap_err();
+        assert_eq!(ParseError::new(2, "`#` or `.`", "o").on_line(2), error);
+    }
+}
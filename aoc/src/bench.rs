@@ -0,0 +1,170 @@
+use std::fmt::{self, Write};
+use std::time::Duration;
+
+use aoc_core::{DynSolver, ParseError, Part};
+use serde::Serialize;
+
+/// Summary statistics over repeated timings of the same stage.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub max: Duration,
+    /// Population standard deviation.
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Panics if `times` is empty.
+    pub fn new(mut times: Vec<Duration>) -> Self {
+        assert!(!times.is_empty(), "no timings to summarise");
+        times.sort();
+
+        let runs = times.len();
+        let median = if runs.is_multiple_of(2) {
+            (times[runs / 2 - 1] + times[runs / 2]) / 2
+        } else {
+            times[runs / 2]
+        };
+        let mean = times.iter().sum::<Duration>() / runs as u32;
+        let variance = times.iter()
+            .map(|t| (t.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>() / runs as f64;
+
+        Stats {
+            runs,
+            min: times[0],
+            median,
+            mean,
+            max: times[runs - 1],
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// The part of a day being timed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part(part) => write!(f, "part{}", part),
+        }
+    }
+}
+
+/// The timings of one stage of one day.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Row {
+    pub day: u8,
+    pub stage: Stage,
+    pub stats: Stats,
+}
+
+/// Runs a day `runs` times, parsing afresh each time, and summarises each stage.
+pub fn bench(solver: &dyn DynSolver, input: &str, parts: &[Part], runs: usize) -> Result<Vec<Row>, ParseError> {
+    let mut parse = Vec::with_capacity(runs);
+    let mut by_part = vec![Vec::with_capacity(runs); parts.len()];
+    for _ in 0..runs.max(1) {
+        let timings = solver.time(input, parts)?;
+        parse.push(timings.parse);
+        for (times, (_, time)) in by_part.iter_mut().zip(timings.parts) {
+            times.push(time);
+        }
+    }
+
+    let mut rows = vec![Row { day: solver.day(), stage: Stage::Parse, stats: Stats::new(parse) }];
+    for (&part, times) in parts.iter().zip(by_part) {
+        rows.push(Row { day: solver.day(), stage: Stage::Part(part), stats: Stats::new(times) });
+    }
+    Ok(rows)
+}
+
+/// Lays the rows out as a table with a header line.
+pub fn table(rows: &[Row]) -> String {
+    let mut table = format!(
+        "{:>3}  {:<5}  {:>4}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}\n",
+        "day", "stage", "runs", "min", "median", "mean", "max", "stddev",
+    );
+    for Row { day, stage, stats } in rows {
+        let cells: Vec<_> = [stats.min, stats.median, stats.mean, stats.max, stats.stddev]
+            .iter()
+            .map(|time| format!("{:>10}", format!("{:.2?}", time)))
+            .collect();
+        writeln!(table, "{:>3}  {:<5}  {:>4}  {}", day, stage.to_string(), stats.runs, cells.join("  ")).unwrap();
+    }
+    table
+}
+
+#[derive(Serialize)]
+struct Record {
+    day: u8,
+    stage: String,
+    runs: usize,
+    min_ns: u64,
+    median_ns: u64,
+    mean_ns: u64,
+    max_ns: u64,
+    stddev_ns: u64,
+}
+
+/// The rows as JSON, one object per line, with times in nanoseconds.
+pub fn json(rows: &[Row]) -> String {
+    let nanos = |time: Duration| time.as_nanos() as u64;
+    let mut json = String::new();
+    for Row { day, stage, stats } in rows {
+        let record = Record {
+            day: *day,
+            stage: stage.to_string(),
+            runs: stats.runs,
+            min_ns: nanos(stats.min),
+            median_ns: nanos(stats.median),
+            mean_ns: nanos(stats.mean),
+            max_ns: nanos(stats.max),
+            stddev_ns: nanos(stats.stddev),
+        };
+        writeln!(json, "{}", serde_json::to_string(&record).unwrap()).unwrap();
+    }
+    json
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn millis(times: &[u64]) -> Vec<Duration> {
+        times.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::new(millis(&[4, 2, 8, 6]));
+        assert_eq!(4, stats.runs);
+        assert_eq!(Duration::from_millis(2), stats.min);
+        assert_eq!(Duration::from_millis(5), stats.median);
+        assert_eq!(Duration::from_millis(5), stats.mean);
+        assert_eq!(Duration::from_millis(8), stats.max);
+        assert_eq!(2236, stats.stddev.as_micros());
+
+        assert_eq!(Duration::from_millis(3), Stats::new(millis(&[9, 3, 1])).median);
+    }
+
+    #[test]
+    fn test_bench() {
+        let rows = bench(&day13::Day13, "939\n7,13,x,x,59,x,31,19", &[Part::Two], 3).unwrap();
+        let stages: Vec<_> = rows.iter().map(|row| (row.stage, row.stats.runs)).collect();
+        assert_eq!(vec![(Stage::Parse, 3), (Stage::Part(Part::Two), 3)], stages);
+
+        assert_eq!(3, table(&rows).lines().count());
+        let json = json(&rows);
+        let record: serde_json::Value = serde_json::from_str(json.lines().nth(1).unwrap()).unwrap();
+        assert_eq!("part2", record["stage"]);
+    }
+}
@@ -0,0 +1,140 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use aoc_core::{DynSolver, Part};
+
+/// The known answers to a day's puzzle, as kept in its `answers` file.
+///
+/// The file has the part 1 answer on its first line and the part 2 answer on its second. A
+/// missing or blank line means that answer isn't known yet.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Expected([Option<String>; 2]);
+
+impl Expected {
+    pub fn parse(text: &str) -> Self {
+        let mut lines = text.lines().map(|line| Some(line.trim()).filter(|line| !line.is_empty()).map(str::to_owned));
+        Expected([lines.next().flatten(), lines.next().flatten()])
+    }
+
+    /// Reads an answers file, treating a missing file as knowing no answers.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => Ok(Expected::parse(&text)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Expected::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.0[0].as_deref(),
+            Part::Two => self.0[1].as_deref(),
+        }
+    }
+}
+
+/// How one part of a day fared against its known answer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    Pass,
+    Mismatch { expected: String, found: String },
+    /// The part was solved but there is no answer on record to compare it with.
+    Unchecked(String),
+    /// The input could not be read or parsed.
+    Fail(String),
+}
+
+#[derive(Clone, Debug)]
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        !matches!(self.outcome, Outcome::Mismatch { .. } | Outcome::Fail(_))
+    }
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Day {} part {}: ", self.day, self.part)?;
+        match &self.outcome {
+            Outcome::Pass => write!(f, "pass")?,
+            Outcome::Mismatch { expected, found } => write!(f, "MISMATCH, expected {} but got {}", expected, found)?,
+            Outcome::Unchecked(found) => write!(f, "no answer on record, got {}", found)?,
+            Outcome::Fail(error) => return write!(f, "FAIL, {}", error),
+        }
+        write!(f, " ({:.2?})", self.elapsed)
+    }
+}
+
+/// Solves each of `parts` from `input` and compares the answers with `expected`.
+///
+/// Each part is timed on its own, parse included.
+pub fn check(solver: &dyn DynSolver, input: &str, expected: &Expected, parts: &[Part]) -> Vec<Check> {
+    parts.iter().map(|&part| {
+        let start = Instant::now();
+        let solved = solver.solve(input, &[part]);
+        let elapsed = start.elapsed();
+
+        let outcome = match solved {
+            Ok(answers) => {
+                let found = answers.into_iter().next().map(|answer| answer.value).unwrap_or_default();
+                match expected.get(part) {
+                    Some(expected) if expected == found => Outcome::Pass,
+                    Some(expected) => Outcome::Mismatch { expected: expected.to_owned(), found },
+                    None => Outcome::Unchecked(found),
+                }
+            }
+            Err(e) => Outcome::Fail(e.to_string()),
+        };
+        Check { day: solver.day(), part, outcome, elapsed }
+    }).collect()
+}
+
+/// A failed check for each of `parts`, for when the day's input can't be read.
+pub fn failed(solver: &dyn DynSolver, error: &str, parts: &[Part]) -> Vec<Check> {
+    parts.iter().map(|&part| Check {
+        day: solver.day(),
+        part,
+        outcome: Outcome::Fail(error.to_owned()),
+        elapsed: Duration::default(),
+    }).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_expected() {
+        let expected = Expected::parse("1016964\n182588480\n");
+        assert_eq!(Some("1016964"), expected.get(Part::One));
+        assert_eq!(Some("182588480"), expected.get(Part::Two));
+
+        let expected = Expected::parse("\n3649");
+        assert_eq!(None, expected.get(Part::One));
+        assert_eq!(Some("3649"), expected.get(Part::Two));
+
+        assert_eq!(Expected::default(), Expected::load(Path::new("no/such/answers")).unwrap());
+    }
+
+    #[test]
+    fn test_check() {
+        let expected = Expected::parse("5\n");
+        let checks = check(&day13::Day13, "939\n7,13,x,x,59,x,31,19", &expected, &Part::BOTH);
+        assert_eq!(Outcome::Mismatch { expected: "5".to_owned(), found: "295".to_owned() }, checks[0].outcome);
+        assert_eq!(Outcome::Unchecked("1068781".to_owned()), checks[1].outcome);
+        assert!(!checks[0].passed());
+        assert!(checks[1].passed());
+
+        let checks = check(&day13::Day13, "939\n7,y", &expected, &[Part::One]);
+        assert!(matches!(checks[0].outcome, Outcome::Fail(_)));
+    }
+}
@@ -0,0 +1,116 @@
+use aoc_core::{generate::Generated, repl::DynExplore, DynSolver};
+use aoc_export::DynExport;
+use rand::rngs::StdRng;
+
+/// Every day's solver, in order.
+pub const DAYS: [&dyn DynSolver; 25] = [
+    &day1::Day1,
+    &day2::Day2,
+    &day3::Day3,
+    &day4::Day4,
+    &day5::Day5,
+    &day6::Day6,
+    &day7::Day7,
+    &day8::Day8,
+    &day9::Day9,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+    &day17::Day17,
+    &day18::Day18,
+    &day19::Day19,
+    &day20::Day20,
+    &day21::Day21,
+    &day22::Day22,
+    &day23::Day23,
+    &day24::Day24,
+    &day25::Day25,
+];
+
+/// Makes a random input for a day, of a size that means something different for each day.
+pub type Generator = fn(&mut StdRng, usize) -> Generated;
+
+/// Every day's input generator and the size it uses by default, in order.
+pub const GENERATORS: [(Generator, usize); 25] = [
+    (day1::generate::generate::<StdRng>, day1::generate::DEFAULT_SIZE),
+    (day2::generate::generate::<StdRng>, day2::generate::DEFAULT_SIZE),
+    (day3::generate::generate::<StdRng>, day3::generate::DEFAULT_SIZE),
+    (day4::generate::generate::<StdRng>, day4::generate::DEFAULT_SIZE),
+    (day5::generate::generate::<StdRng>, day5::generate::DEFAULT_SIZE),
+    (day6::generate::generate::<StdRng>, day6::generate::DEFAULT_SIZE),
+    (day7::generate::generate::<StdRng>, day7::generate::DEFAULT_SIZE),
+    (day8::generate::generate::<StdRng>, day8::generate::DEFAULT_SIZE),
+    (day9::generate::generate::<StdRng>, day9::generate::DEFAULT_SIZE),
+    (day10::generate::generate::<StdRng>, day10::generate::DEFAULT_SIZE),
+    (day11::generate::generate::<StdRng>, day11::generate::DEFAULT_SIZE),
+    (day12::generate::generate::<StdRng>, day12::generate::DEFAULT_SIZE),
+    (day13::generate::generate::<StdRng>, day13::generate::DEFAULT_SIZE),
+    (day14::generate::generate::<StdRng>, day14::generate::DEFAULT_SIZE),
+    (day15::generate::generate::<StdRng>, day15::generate::DEFAULT_SIZE),
+    (day16::generate::generate::<StdRng>, day16::generate::DEFAULT_SIZE),
+    (day17::generate::generate::<StdRng>, day17::generate::DEFAULT_SIZE),
+    (day18::generate::generate::<StdRng>, day18::generate::DEFAULT_SIZE),
+    (day19::generate::generate::<StdRng>, day19::generate::DEFAULT_SIZE),
+    (day20::generate::generate::<StdRng>, day20::generate::DEFAULT_SIZE),
+    (day21::generate::generate::<StdRng>, day21::generate::DEFAULT_SIZE),
+    (day22::generate::generate::<StdRng>, day22::generate::DEFAULT_SIZE),
+    (day23::generate::generate::<StdRng>, day23::generate::DEFAULT_SIZE),
+    (day24::generate::generate::<StdRng>, day24::generate::DEFAULT_SIZE),
+    (day25::generate::generate::<StdRng>, day25::generate::DEFAULT_SIZE),
+];
+
+/// The days that can draw their puzzles, in order.
+pub const EXPORTS: [&dyn DynExport; 4] = [
+    &day11::Day11,
+    &day17::Day17,
+    &day20::Day20,
+    &day24::Day24,
+];
+
+/// The days that can be explored from the REPL, in order.
+pub const EXPLORES: [&dyn DynExplore; 6] = [
+    &day8::Day8,
+    &day11::Day11,
+    &day17::Day17,
+    &day18::Day18,
+    &day19::Day19,
+    &day24::Day24,
+];
+
+pub fn get(day: u8) -> Option<&'static dyn DynSolver> {
+    DAYS.iter().copied().find(|solver| solver.day() == day)
+}
+
+pub fn get_export(day: u8) -> Option<&'static dyn DynExport> {
+    EXPORTS.iter().copied().find(|export| export.day() == day)
+}
+
+pub fn get_explore(day: u8) -> Option<&'static dyn DynExplore> {
+    EXPLORES.iter().copied().find(|explore| explore.day() == day)
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+    use std::path::Path;
+
+    use super::*;
+
+    /// Every saved fuzzing input must parse or fail to parse, but never panic.
+    #[test]
+    fn test_fuzz_corpus() {
+        let corpus = Path::new(env!("CARGO_MANIFEST_DIR")).join("../fuzz/corpus");
+        for solver in DAYS.iter() {
+            for entry in fs::read_dir(corpus.join(format!("day{}", solver.day()))).unwrap() {
+                let bytes = fs::read(entry.unwrap().path()).unwrap();
+                if let Ok(input) = std::str::from_utf8(&bytes) {
+                    let _ = solver.time(input, &[]);
+                }
+            }
+        }
+    }
+}
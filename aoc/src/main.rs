@@ -0,0 +1,373 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::str::FromStr;
+
+use aoc_core::{generate::Generated, DynSolver, ParseError, Part};
+use aoc_core::input::{self, InputArgs, Source};
+use aoc_core::output::{Format, OutputArgs};
+use aoc_core::repl;
+use aoc_export::{DynExport, Frames};
+use check::Expected;
+use clap::{Parser, Subcommand};
+use rand::{rngs::StdRng, SeedableRng};
+
+mod bench;
+mod check;
+mod days;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2020 solutions")]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, or every day
+    Run {
+        /// Day number, or `all`
+        day: Days,
+        /// Only solve this part (1 or 2)
+        #[arg(long)]
+        part: Option<Part>,
+        #[command(flatten)]
+        input: InputArgs,
+        /// Directory holding a `dayN/input` file for each day
+        #[arg(long, value_name = "DIR", default_value = ".")]
+        input_dir: PathBuf,
+        /// Compare the answers with the `dayN/answers` files and report any mismatches
+        #[arg(long)]
+        check: bool,
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+    /// Time parsing and each part of one day, or every day, over repeated runs
+    Bench {
+        /// Day number, or `all`
+        day: Days,
+        /// Only time this part (1 or 2)
+        #[arg(long)]
+        part: Option<Part>,
+        #[command(flatten)]
+        input: InputArgs,
+        /// Directory holding a `dayN/input` file for each day
+        #[arg(long, value_name = "DIR", default_value = ".")]
+        input_dir: PathBuf,
+        /// How many times to run each day
+        #[arg(long, default_value_t = 10)]
+        runs: usize,
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+    /// Make random puzzle inputs, with their answers where they're known, for stress testing
+    Generate {
+        /// Day number, or `all`
+        day: Days,
+        /// How big an input to make; what this counts differs from day to day
+        #[arg(long)]
+        size: Option<usize>,
+        /// Seed for the random numbers, to make the same inputs again
+        #[arg(long)]
+        seed: Option<u64>,
+        /// Write a `dayN/input` and `dayN/answers` file for each day under this directory,
+        /// rather than printing the input
+        #[arg(long, value_name = "DIR")]
+        out: Option<PathBuf>,
+    },
+    /// Draw a day's puzzle as it's solved, as PPM, PGM, PBM or SVG images
+    Export {
+        /// Day number: 11, 17, 20 or 24
+        day: u8,
+        /// Which part's solving to draw (1 or 2)
+        #[arg(long, default_value_t = Part::One)]
+        part: Part,
+        #[command(flatten)]
+        input: InputArgs,
+        /// Directory holding a `dayN/input` file for each day
+        #[arg(long, value_name = "DIR", default_value = ".")]
+        input_dir: PathBuf,
+        /// File to write the final picture to, or with `--frames` a directory for every frame
+        #[arg(long, value_name = "PATH")]
+        out: PathBuf,
+        /// Image format (ppm, pgm, pbm or svg), by default taken from the extension of `--out`
+        /// and otherwise ppm
+        #[arg(long)]
+        format: Option<aoc_export::Format>,
+        /// Write every step as a numbered frame in the `--out` directory, for making animations
+        #[arg(long)]
+        frames: bool,
+    },
+    /// Load a day's input and explore it one command at a time; `help` lists the commands
+    Repl {
+        /// Day number: 8, 11, 17, 18, 19 or 24
+        day: u8,
+        #[command(flatten)]
+        input: InputArgs,
+        /// Directory holding a `dayN/input` file for each day
+        #[arg(long, value_name = "DIR", default_value = ".")]
+        input_dir: PathBuf,
+    },
+}
+
+#[derive(Clone, Copy)]
+enum Days {
+    All,
+    One(u8),
+}
+
+impl Days {
+    fn numbers(self) -> Vec<u8> {
+        self.solvers().iter().map(|solver| solver.day()).collect()
+    }
+
+    fn solvers(self) -> Vec<&'static dyn DynSolver> {
+        match self {
+            Days::All => days::DAYS.to_vec(),
+            Days::One(day) => days::get(day).into_iter().collect(),
+        }
+    }
+}
+
+impl FromStr for Days {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Days::All);
+        }
+        match s.parse() {
+            Ok(day) if days::get(day).is_some() => Ok(Days::One(day)),
+            _ => Err(format!("no solver for day `{}`", s)),
+        }
+    }
+}
+
+fn main() {
+    let args = Args::parse();
+
+    match args.command {
+        Command::Run { day, part, input, input_dir, check, output } => {
+            output.apply();
+            let source = explicit_source(day, &input);
+            if check {
+                let passed = run_checks(day, Part::selected(part), source.as_ref(), &input_dir);
+                process::exit(if passed { 0 } else { 1 });
+            }
+
+            let solved = for_each_day(day, source.as_ref(), &input_dir, |solver, input| {
+                for answer in solver.solve(input, Part::selected(part))? {
+                    match output.format {
+                        Format::Text => println!("{}", answer),
+                        Format::Json => println!("{}", answer.to_json()),
+                    }
+                }
+                Ok(())
+            });
+            if !solved {
+                process::exit(1);
+            }
+        }
+        Command::Bench { day, part, input, input_dir, runs, output } => {
+            output.apply();
+            let source = explicit_source(day, &input);
+            let mut rows = Vec::new();
+            let timed = for_each_day(day, source.as_ref(), &input_dir, |solver, input| {
+                rows.extend(bench::bench(solver, input, Part::selected(part), runs)?);
+                Ok(())
+            });
+
+            match output.format {
+                Format::Text => print!("{}", bench::table(&rows)),
+                Format::Json => print!("{}", bench::json(&rows)),
+            }
+            if !timed {
+                process::exit(1);
+            }
+        }
+        Command::Generate { day, size, seed, out } => {
+            if out.is_none() && matches!(day, Days::All) {
+                eprintln!("generating every day needs a directory to write to, given with --out");
+                process::exit(2);
+            }
+            let seed = seed.unwrap_or_else(rand::random);
+            eprintln!("seed {}", seed);
+
+            for day in day.numbers() {
+                let (generate, default_size) = days::GENERATORS[day as usize - 1];
+                // Seeded per day, so a day comes out the same whether it's made alone or with the rest.
+                let generated = generate(&mut StdRng::seed_from_u64(seed.wrapping_add(day as u64)), size.unwrap_or(default_size));
+                match &out {
+                    Some(out) => {
+                        if let Err(e) = write_generated(out, day, &generated) {
+                            eprintln!("Day {}: could not write to {}: {}", day, out.display(), e);
+                            process::exit(1);
+                        }
+                    }
+                    None => {
+                        print!("{}", generated.input);
+                        for &part in &Part::BOTH {
+                            let answer = generated.answer(part).unwrap_or("unknown");
+                            eprintln!("Day {} part {}: {}", day, part, answer);
+                        }
+                    }
+                }
+            }
+        }
+        Command::Export { day, part, input, input_dir, out, format, frames } => {
+            let exporter = match days::get_export(day) {
+                Some(exporter) => exporter,
+                None => {
+                    eprintln!("day {} can't be drawn; only days 11, 17, 20 and 24 can", day);
+                    process::exit(2);
+                }
+            };
+            let source = input.source().unwrap_or_else(|| default_source(exporter, &input_dir));
+            let input = source.read().unwrap_or_else(|e| {
+                eprintln!("Day {}: {}", day, e);
+                process::exit(1);
+            });
+
+            let format = format.or_else(|| aoc_export::Format::from_path(&out)).unwrap_or(aoc_export::Format::Ppm);
+            let sink = if frames { Frames::sequence(&out, format) } else { Ok(Frames::file(&out, format)) };
+            match export(exporter, &input, part, sink) {
+                Ok(count) if frames => eprintln!("Day {} part {}: wrote {} frames to {}", day, part, count, out.display()),
+                Ok(_) => eprintln!("Day {} part {}: wrote {}", day, part, out.display()),
+                Err(aoc_export::Error::Parse(e)) => {
+                    eprint!("Day {}: {}", day, e.render(&source.to_string(), &input));
+                    process::exit(1);
+                }
+                Err(aoc_export::Error::Io(e)) => {
+                    eprintln!("Day {}: could not write to {}: {}", day, out.display(), e);
+                    process::exit(1);
+                }
+            }
+        }
+        Command::Repl { day, input, input_dir } => {
+            let explorer = match days::get_explore(day) {
+                Some(explorer) => explorer,
+                None => {
+                    eprintln!("day {} can't be explored; only days 8, 11, 17, 18, 19 and 24 can", day);
+                    process::exit(2);
+                }
+            };
+            let source = input.source().unwrap_or_else(|| default_source(explorer, &input_dir));
+            if source == Source::Stdin {
+                eprintln!("the REPL reads its commands from stdin, so the input must come from elsewhere");
+                process::exit(2);
+            }
+            let input = source.read().unwrap_or_else(|e| {
+                eprintln!("Day {}: {}", day, e);
+                process::exit(1);
+            });
+            let mut session = explorer.explore(&input).unwrap_or_else(|e| {
+                eprint!("Day {}: {}", day, e.render(&source.to_string(), &input));
+                process::exit(1);
+            });
+
+            let prompt = format!("day{}> ", day);
+            if let Err(e) = repl::run(session.as_mut(), &prompt, io::stdin().lock(), io::stdout()) {
+                eprintln!("Day {}: {}", day, e);
+                process::exit(1);
+            }
+        }
+    }
+}
+
+/// Draws one day's puzzle into `frames`, returning how many frames it drew.
+fn export(
+    export: &dyn DynExport,
+    input: &str,
+    part: Part,
+    frames: io::Result<Frames>,
+) -> Result<usize, aoc_export::Error> {
+    let mut frames = frames?;
+    export.export(input, part, &mut frames)?;
+    Ok(frames.finish()?)
+}
+
+/// Writes a generated input and its answers where `aoc run --input-dir` will look for them.
+fn write_generated(dir: &Path, day: u8, generated: &Generated) -> io::Result<()> {
+    let dir = dir.join(format!("day{}", day));
+    fs::create_dir_all(&dir)?;
+    fs::write(dir.join("input"), &generated.input)?;
+    fs::write(dir.join("answers"), generated.answers_file())
+}
+
+/// The input given on the command line, which only makes sense for a single day.
+fn explicit_source(day: Days, input: &InputArgs) -> Option<Source> {
+    let source = input.source();
+    if source.is_some() && matches!(day, Days::All) {
+        eprintln!("an explicit input can only be used to run a single day");
+        process::exit(2);
+    }
+    source
+}
+
+/// Reads each selected day's input and hands it to `f`, reporting days whose input can't be read
+/// or parsed.
+///
+/// Returns whether every day went through.
+fn for_each_day(
+    day: Days,
+    source: Option<&Source>,
+    input_dir: &Path,
+    mut f: impl FnMut(&dyn DynSolver, &str) -> Result<(), ParseError>,
+) -> bool {
+    let mut ok = true;
+    for solver in day.solvers() {
+        let source = source.cloned().unwrap_or_else(|| default_source(solver, input_dir));
+        let input = match source.read() {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Day {}: {}", solver.day(), e);
+                ok = false;
+                continue;
+            }
+        };
+        if let Err(e) = f(solver, &input) {
+            eprint!("Day {}: {}", solver.day(), e.render(&source.to_string(), &input));
+            ok = false;
+        }
+    }
+    ok
+}
+
+/// Checks every selected day against its answers file, printing a line per part.
+///
+/// Returns whether every answer on record matched.
+fn run_checks(day: Days, parts: &[Part], source: Option<&Source>, input_dir: &Path) -> bool {
+    let mut checks = Vec::new();
+    for solver in day.solvers() {
+        let source = source.cloned().unwrap_or_else(|| default_source(solver, input_dir));
+        let answers = input_dir.join(format!("day{}", solver.day())).join("answers");
+
+        let day_checks = match (source.read(), Expected::load(&answers)) {
+            (Ok(input), Ok(expected)) => check::check(solver, &input, &expected, parts),
+            (Err(e), _) => check::failed(solver, &e.to_string(), parts),
+            (_, Err(e)) => check::failed(solver, &format!("could not read {}: {}", answers.display(), e), parts),
+        };
+        for check in &day_checks {
+            println!("{}", check);
+        }
+        checks.extend(day_checks);
+    }
+
+    let count = |f: fn(&check::Outcome) -> bool| checks.iter().filter(|c| f(&c.outcome)).count();
+    println!(
+        "{} passed, {} mismatched, {} failed, {} unchecked",
+        count(|o| matches!(o, check::Outcome::Pass)),
+        count(|o| matches!(o, check::Outcome::Mismatch { .. })),
+        count(|o| matches!(o, check::Outcome::Fail(_))),
+        count(|o| matches!(o, check::Outcome::Unchecked(_))),
+    );
+    checks.iter().all(check::Check::passed)
+}
+
+/// The day's input file under `input_dir`, falling back to any input baked into the solver.
+fn default_source(solver: &dyn DynSolver, input_dir: &Path) -> Source {
+    let path = input_dir.join(format!("day{}", solver.day())).join("input");
+    input::file_or_default(&path, solver.default_input())
+}
@@ -0,0 +1,74 @@
+use aoc_core::generate::Generated;
+use rand::{seq::SliceRandom, Rng};
+
+use crate::TARGET;
+
+/// Number of entries in a generated expense report, which can be from 5 to 500.
+pub const DEFAULT_SIZE: usize = 200;
+
+/// An expense report of `size` entries in which exactly one pair and one triple sum to
+/// [`TARGET`].
+///
+/// The pair and triple are planted first, then the rest of the entries are drawn so that they
+/// can't make another sum with anything already in the report, even by using an entry twice.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+    let (pair, triple) = loop {
+        let a = rng.gen_range(1..TARGET / 2);
+        let (x, y) = (rng.gen_range(1..TARGET / 2), rng.gen_range(1..TARGET / 2));
+        let pair = [a, TARGET - a];
+        let triple = [x, y, TARGET - x - y];
+        if triple[2] > 0 && sums(&[&pair[..], &triple].concat()) == (1, 1) {
+            break (pair, triple);
+        }
+    };
+
+    let mut entries: Vec<_> = pair.iter().chain(&triple).copied().collect();
+    while entries.len() < size.clamp(5, 500) {
+        let low = if rng.gen_bool(0.9) { TARGET / 2 } else { 1 };
+        let entry = rng.gen_range(low..TARGET);
+        if !entries.contains(&entry) && !makes_sum(&entries, entry) {
+            entries.push(entry);
+        }
+    }
+    entries.shuffle(rng);
+
+    let input = entries.iter().map(|entry| format!("{}\n", entry)).collect();
+    let part1 = pair.iter().product::<i64>();
+    let part2 = triple.iter().product::<i64>();
+    Generated::new(input, Some(part1.to_string()), Some(part2.to_string()))
+}
+
+/// How many pairs and triples of `entries` add up to the target, counting any that use the same
+/// entry more than once.
+fn sums(entries: &[i64]) -> (usize, usize) {
+    let n = entries.len();
+    let (mut pairs, mut triples) = (0, 0);
+    for i in 0..n {
+        for j in i..n {
+            pairs += (entries[i] + entries[j] == TARGET) as usize;
+            triples += (j..n).filter(|&k| entries[i] + entries[j] + entries[k] == TARGET).count();
+        }
+    }
+    (pairs, triples)
+}
+
+/// Whether `entry` adds up to the target with one or two of `entries`, or with itself.
+fn makes_sum(entries: &[i64], entry: i64) -> bool {
+    let others = [entries, &[entry]].concat();
+    others.iter().any(|&a| a + entry == TARGET || others.iter().any(|&b| a + b + entry == TARGET))
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day1;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..20 {
+            generate(&mut StdRng::seed_from_u64(seed), 50).assert_solves::<Day1>();
+        }
+    }
+}
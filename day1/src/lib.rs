@@ -0,0 +1,213 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use aoc_core::{diag, ParseError, Solver};
+use num_bigint::BigInt;
+
+#[cfg(feature = "generate")]
+pub mod generate;
+pub mod stream;
+
+/// The sum the expense report entries have to add up to.
+pub const TARGET: i64 = 2020;
+
+/// Day 1: Report Repair
+pub struct Day1;
+
+impl Solver for Day1 {
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<i64>;
+    type Answer1 = BigInt;
+    type Answer2 = BigInt;
+
+    /// Lines that aren't numbers are skipped, and listed as diagnostics.
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input.lines()
+            .enumerate()
+            .filter_map(|(index, line)| {
+                let entry = parse_entry(line);
+                if entry.is_none() {
+                    diag!(1, "line {}: skipping `{}`, which isn't a number", index + 1, line);
+                }
+                entry
+            })
+            .collect())
+    }
+
+    fn part1(entries: &Self::Input<'_>) -> BigInt {
+        get_answer(entries)
+    }
+
+    fn part2(entries: &Self::Input<'_>) -> BigInt {
+        get_answer2(entries)
+    }
+}
+
+/// One line of the expense report, ignoring any whitespace around it.
+pub fn parse_entry(line: &str) -> Option<i64> {
+    line.trim().parse().ok()
+}
+
+fn get_answer(entries: &[i64]) -> BigInt {
+    answer(entries, 2)
+}
+
+fn get_answer2(entries: &[i64]) -> BigInt {
+    answer(entries, 3)
+}
+
+/// The product of the first `k` entries found that add up to [`TARGET`].
+fn answer(entries: &[i64], k: usize) -> BigInt {
+    let sums = find_k_sum(entries, k, TARGET)
+        .unwrap_or_else(|| panic!("no {} entries add up to {}", k, TARGET));
+    product(&sums[0])
+}
+
+/// The product of `entries`, which can take more than an `i128` once there are three of them.
+pub fn product(entries: &[i64]) -> BigInt {
+    entries.iter().copied().map(BigInt::from).product()
+}
+
+/// Every way of picking `k` of `entries` that add up to `target`, each in ascending order, or
+/// `None` if there isn't one.
+///
+/// An entry can only be picked as many times as it appears. Combinations are listed once each,
+/// in order, however many ways there are of picking their entries.
+///
+/// Pairs are found with a hash set in linear time. Larger combinations are split in two: every
+/// combination of the smaller half's size, `k / 2`, is filed by its sum, then looked up from each
+/// combination of the larger half's size, which takes around `n^ceil(k/2)` time rather than
+/// `n^k`. That's `n^2` for triples, the same as for 4 entries.
+pub fn find_k_sum(entries: &[i64], k: usize, target: i64) -> Option<Vec<Vec<i64>>> {
+    let mut entries = entries.to_vec();
+    entries.sort_unstable();
+
+    let sums = match k {
+        0 if target == 0 => vec![vec![]],
+        0 => vec![],
+        1 => entries.iter().filter(|&&entry| entry == target).take(1).map(|&entry| vec![entry]).collect(),
+        2 => two_sum(&entries, target),
+        _ => meet_in_the_middle(&entries, k, target.into()),
+    };
+    Some(sums).filter(|sums| !sums.is_empty())
+}
+
+/// Pairs of sorted `entries` that add up to `target`.
+fn two_sum(entries: &[i64], target: i64) -> Vec<Vec<i64>> {
+    let mut seen = HashSet::new();
+    let mut pairs = BTreeSet::new();
+    for &entry in entries {
+        // An entry too far from the target for the difference to fit can't be part of a pair.
+        if let Some(other) = target.checked_sub(entry).filter(|other| seen.contains(other)) {
+            pairs.insert(vec![other, entry]);
+        }
+        seen.insert(entry);
+    }
+    pairs.into_iter().collect()
+}
+
+/// Combinations of `k` sorted `entries` that add up to `target`, for `k` of 3 or more.
+///
+/// Each combination of positions is found exactly once, as its lowest `k / 2` positions followed
+/// by the rest.
+///
+/// Sums are taken as `i128`s so they can't overflow.
+fn meet_in_the_middle(entries: &[i64], k: usize, target: i128) -> Vec<Vec<i64>> {
+    let (low, high) = (k / 2, k - k / 2);
+
+    let mut by_sum: HashMap<i128, Vec<Vec<usize>>> = HashMap::new();
+    for_each_combination(entries.len(), low, &mut |positions| {
+        by_sum.entry(sum(entries, positions)).or_default().push(positions.to_vec());
+    });
+
+    let mut sums = BTreeSet::new();
+    for_each_combination(entries.len(), high, &mut |positions| {
+        let lows = by_sum.get(&(target - sum(entries, positions))).map_or(&[][..], Vec::as_slice);
+        for low in lows.iter().filter(|low| low[low.len() - 1] < positions[0]) {
+            sums.insert(low.iter().chain(positions).map(|&i| entries[i]).collect());
+        }
+    });
+    sums.into_iter().collect()
+}
+
+fn sum(entries: &[i64], positions: &[usize]) -> i128 {
+    positions.iter().map(|&i| i128::from(entries[i])).sum()
+}
+
+/// Calls `f` with every ascending choice of `k` positions out of `n`.
+fn for_each_combination(n: usize, k: usize, f: &mut impl FnMut(&[usize])) {
+    fn extend(n: usize, k: usize, positions: &mut Vec<usize>, f: &mut impl FnMut(&[usize])) {
+        if positions.len() == k {
+            return f(positions);
+        }
+        let next = positions.last().map_or(0, |last| last + 1);
+        // Leave enough positions after this one to fill the rest.
+        for position in next..=n.saturating_sub(k - positions.len()) {
+            positions.push(position);
+            extend(n, k, positions, f);
+            positions.pop();
+        }
+    }
+    if k <= n {
+        extend(n, k, &mut Vec::with_capacity(k), f);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &[i64] = &[1721, 979, 366, 299, 675, 1456];
+
+    #[test]
+    fn test_answers() {
+        assert_eq!(BigInt::from(514579), get_answer(INPUT));
+        assert_eq!(BigInt::from(241861950), get_answer2(INPUT));
+    }
+
+    #[test]
+    fn test_wide_answers() {
+        let entries = Day1::parse("4000000000000000000\n-4000000000000000000\n2020\n0").unwrap();
+        assert_eq!(BigInt::from(0), Day1::part1(&entries));
+        let expected: BigInt = "-32320000000000000000000000000000000000000".parse().unwrap();
+        assert_eq!(expected, Day1::part2(&entries));
+        let expected: BigInt = "-255211775190703847569860839463261831168".parse().unwrap();
+        assert_eq!(expected, product(&[i64::MIN, 3, i64::MAX]));
+    }
+
+    #[test]
+    fn test_find_k_sum() {
+        assert_eq!(Some(vec![vec![299, 1721]]), find_k_sum(INPUT, 2, 2020));
+        assert_eq!(Some(vec![vec![366, 675, 979]]), find_k_sum(INPUT, 3, 2020));
+        assert_eq!(Some(vec![vec![1, 3, 5], vec![2, 3, 4]]), find_k_sum(&[5, 4, 3, 2, 1], 3, 9));
+        assert_eq!(Some(vec![vec![1, 2, 3, 4]]), find_k_sum(&[5, 4, 3, 2, 1], 4, 10));
+        assert_eq!(Some(vec![vec![1, 2]]), find_k_sum(&[1, 2, 2, 1], 2, 3));
+        assert_eq!(Some(vec![vec![-5, 0, 5]]), find_k_sum(&[5, 0, -5], 3, 0));
+        assert_eq!(Some(vec![vec![]]), find_k_sum(INPUT, 0, 0));
+        assert_eq!(None, find_k_sum(INPUT, 2, 3));
+        assert_eq!(None, find_k_sum(INPUT, 7, 2020));
+    }
+
+    #[test]
+    fn test_wide_entries() {
+        let entries = [i64::MAX, 3, i64::MIN, 5_000_000_000, -4_999_999_999];
+        assert_eq!(Some(vec![vec![-4_999_999_999, 5_000_000_000]]), find_k_sum(&entries, 2, 1));
+        assert_eq!(Some(vec![vec![i64::MIN, 3, i64::MAX]]), find_k_sum(&entries, 3, 2));
+        assert_eq!(Some(vec![vec![i64::MIN, i64::MAX]]), find_k_sum(&entries, 2, -1));
+        assert_eq!(None, find_k_sum(&entries, 2, i64::MIN));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Ok(vec![1721, -3, 12_345_678_901]), Day1::parse("1721\n\nabc\n -3 \n12345678901\n1.5"));
+    }
+
+    /// Entries can only be used as often as they appear.
+    #[test]
+    fn test_find_k_sum_repeats() {
+        assert_eq!(Some(vec![vec![1010, 1010]]), find_k_sum(&[1010, 5, 1010], 2, 2020));
+        assert_eq!(None, find_k_sum(&[1010, 5], 2, 2020));
+        assert_eq!(Some(vec![vec![2, 2, 2, 2]]), find_k_sum(&[2, 2, 2, 2], 4, 8));
+        assert_eq!(None, find_k_sum(&[2, 2, 2], 4, 8));
+    }
+}
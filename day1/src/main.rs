@@ -1,58 +1,100 @@
-use std::cmp::Ordering;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::process;
 
-const TARGET: i32 = 2020;
+use aoc_core::input::Source;
+use aoc_core::output::Format;
+use aoc_core::{DayArgs, Solver};
+use clap::Parser;
+use day1::stream::{self, Event, Search};
+use day1::{find_k_sum, Day1, TARGET};
+use serde_json::json;
 
-fn main() {
-    let file = File::open("input").unwrap();
-    let reader = BufReader::new(file);
-    let entries: Vec<i32> = reader.lines().filter_map(|s| s.unwrap().parse().ok()).collect();
-    let answer = get_answer2(&entries);
-    println!("{}", answer);
+/// Solve day 1 of Advent of Code 2020, or find entries with some other sum
+#[derive(Parser)]
+struct Args {
+    #[command(flatten)]
+    day: DayArgs,
+    /// List every combination of this many entries that adds up to the target, rather than
+    /// solving the puzzle
+    #[arg(long, short, group = "mode")]
+    k: Option<usize>,
+    /// Read the report a line at a time, from stdin unless another input is given, and report
+    /// the first pair and triple that add up to the target as soon as they're read. Looking for
+    /// the triple takes time in proportion to the square of the number of lines until it's found
+    #[arg(long, group = "mode")]
+    stream: bool,
+    /// Only look for the pair with `--stream`, which takes time in proportion to the number of
+    /// lines
+    #[arg(long, requires = "stream")]
+    no_triple: bool,
+    /// The sum to look for with `--k` or `--stream`
+    #[arg(long, short, default_value_t = TARGET, requires = "mode")]
+    target: i64,
 }
 
-fn get_answer(entries: &[i32]) -> i32 {
-    let mut entries: Vec<_> = entries.iter().collect();
-    entries.sort();
-    let count = entries.len();
-    let mut head = 0;
-    let mut foot = count -1;
-
-    loop {
-        let sum = entries[head] + entries[foot];
-        match sum.cmp(&TARGET) {
-            Ordering::Less => {head +=1; continue;}
-            Ordering::Greater => {foot -=1; continue;}
-            Ordering::Equal => {break;}
-        }
+fn main() {
+    let args = Args::parse();
+    args.day.output.apply();
+
+    if args.stream {
+        return stream(&args);
     }
+    let k = match args.k {
+        Some(k) => k,
+        None => return args.day.solve::<Day1>(),
+    };
+    let (source, buf) = args.day.read::<Day1>();
+    let entries = Day1::parse(&buf).unwrap_or_else(|e| {
+        eprint!("{}", e.render(&source.to_string(), &buf));
+        process::exit(1);
+    });
 
-    entries[head] * entries[foot]
+    match find_k_sum(&entries, k, args.target) {
+        Some(sums) => {
+            for sum in sums {
+                match args.day.output.format {
+                    Format::Text => {
+                        let entries: Vec<_> = sum.iter().map(ToString::to_string).collect();
+                        println!("{} = {}", entries.join(" + "), args.target);
+                    }
+                    Format::Json => println!("{}", json!(sum)),
+                }
+            }
+        }
+        None => {
+            eprintln!("no {} entries add up to {}", k, args.target);
+            process::exit(1);
+        }
+    }
 }
 
-fn get_answer2(entries: &[i32]) -> i32 {
-    let mut entries: Vec<_> = entries.iter().collect();
-    entries.sort();
-    let count = entries.len();
-
-    'middle: for middle in entries.iter() {
-        let mut head = 0;
-        let mut foot = count -1;
-    
-        loop {
-            if head == foot {continue 'middle};
-
-            let sum = entries[head] + entries[foot];
-            match sum.cmp(&(TARGET - *middle)) {
-                Ordering::Less => {head +=1; continue;}
-                Ordering::Greater => {foot -=1; continue;}
-                Ordering::Equal => {break;}
-            }
+/// Searches the report as it's read, printing what's found on stdout and the lines skipped on
+/// stderr.
+fn stream(args: &Args) {
+    let source = args.day.input.source().unwrap_or(Source::Stdin);
+    let report = source.open().unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
 
-        }
-    
-        return *middle * entries[head] * entries[foot];
+    let format = args.day.output.format;
+    let search = if args.no_triple { Search::pairs_only(args.target) } else { Search::new(args.target) };
+    let searched = stream::search(report, search, |event| match event {
+        Event::Pair { line, entries } => println!("{}", stream::found(format, line, &entries, args.target)),
+        Event::Triple { line, entries } => println!("{}", stream::found(format, line, &entries, args.target)),
+        Event::Skipped { line, text } => eprintln!("{}:{}: skipping `{}`, which isn't a number", source, line, text),
+    });
+    let search = searched.unwrap_or_else(|e| {
+        eprintln!("could not read {}: {}", source, e);
+        process::exit(1);
+    });
+
+    if search.pair().is_none() {
+        eprintln!("no 2 entries add up to {}", args.target);
+    }
+    if search.triple().is_none() && !args.no_triple {
+        eprintln!("no 3 entries add up to {}", args.target);
+    }
+    if !search.is_done() {
+        process::exit(1);
     }
-    panic!();
 }
@@ -0,0 +1,220 @@
+//! Searching an expense report as it's read, for reports too big to want in memory at once.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::io::{self, BufRead};
+
+use aoc_core::output::Format;
+use serde_json::json;
+
+use crate::{parse_entry, product};
+
+/// Something worth telling about a line of the report, as soon as it's read.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Event {
+    /// The first two entries to add up to the target, the second of them on `line`.
+    Pair { line: usize, entries: [i64; 2] },
+    /// The first three entries to add up to the target, the last of them on `line`.
+    Triple { line: usize, entries: [i64; 3] },
+    /// A line that isn't a number, and was left out.
+    Skipped { line: usize, text: String },
+}
+
+/// The entries read so far, with the first pair and triple of them to add up to the target.
+///
+/// Pairs are checked for in constant time as each entry comes in. Triples take time in
+/// proportion to the number of different entries so far, but stop being looked for once one
+/// has been found. That makes a long report with no triple, or only a late one, take time in
+/// proportion to the square of its length, which [`Search::pairs_only`] avoids.
+#[derive(Clone, Debug)]
+pub struct Search {
+    target: i64,
+    /// Whether to look for a triple at all.
+    triples: bool,
+    /// How many times each entry has been seen.
+    counts: HashMap<i64, usize>,
+    pair: Option<[i64; 2]>,
+    triple: Option<[i64; 3]>,
+}
+
+impl Search {
+    pub fn new(target: i64) -> Self {
+        Search { target, triples: true, counts: HashMap::new(), pair: None, triple: None }
+    }
+
+    /// A search for just the pair, which takes constant time for each entry however many there
+    /// are.
+    pub fn pairs_only(target: i64) -> Self {
+        Search { triples: false, ..Search::new(target) }
+    }
+
+    pub fn pair(&self) -> Option<[i64; 2]> {
+        self.pair
+    }
+
+    pub fn triple(&self) -> Option<[i64; 3]> {
+        self.triple
+    }
+
+    /// Whether both the pair and the triple have been found, or just the pair if that's all
+    /// that's being looked for.
+    pub fn is_done(&self) -> bool {
+        self.pair.is_some() && (self.triple.is_some() || !self.triples)
+    }
+
+    /// Adds `entry` to those seen so far, returning a pair and then a triple if it completes
+    /// the first of either.
+    pub fn push(&mut self, entry: i64) -> (Option<[i64; 2]>, Option<[i64; 3]>) {
+        let pair = if self.pair.is_none() {
+            self.find_pair(entry).map(|other| [other, entry])
+        } else {
+            None
+        };
+        let triple = if self.triples && self.triple.is_none() {
+            self.find_triple(entry).map(|[a, b]| [a, b, entry])
+        } else {
+            None
+        };
+        self.pair = self.pair.or(pair);
+        self.triple = self.triple.or(triple);
+        *self.counts.entry(entry).or_default() += 1;
+        (pair, triple)
+    }
+
+    /// An entry already seen that makes the target with `entry`.
+    fn find_pair(&self, entry: i64) -> Option<i64> {
+        self.target.checked_sub(entry).filter(|other| self.counts.contains_key(other))
+    }
+
+    /// Two entries already seen that make the target with `entry`, with the lowest first entry
+    /// of any such pair so that the same report always gives the same triple.
+    fn find_triple(&self, entry: i64) -> Option<[i64; 2]> {
+        let rest = i128::from(self.target) - i128::from(entry);
+        self.counts.iter().filter_map(|(&a, &count)| {
+            let b = i64::try_from(rest - i128::from(a)).ok()?;
+            // Each pair is only looked at from its lower entry, which is only used twice if it's
+            // been seen twice.
+            let found = match a.cmp(&b) {
+                Ordering::Less => self.counts.contains_key(&b),
+                Ordering::Equal => count >= 2,
+                Ordering::Greater => false,
+            };
+            found.then_some([a, b])
+        }).min()
+    }
+}
+
+/// Reads entries from `report` line by line into `search`, handing each [`Event`] to
+/// `report_event` as it happens.
+///
+/// Stops reading as soon as the search is done.
+pub fn search(report: impl BufRead, mut search: Search, mut report_event: impl FnMut(Event)) -> io::Result<Search> {
+    for (index, line) in report.lines().enumerate() {
+        let line = line?;
+        let entry = match parse_entry(&line) {
+            Some(entry) => entry,
+            None => {
+                report_event(Event::Skipped { line: index + 1, text: line });
+                continue;
+            }
+        };
+
+        let (pair, triple) = search.push(entry);
+        if let Some(entries) = pair {
+            report_event(Event::Pair { line: index + 1, entries });
+        }
+        if let Some(entries) = triple {
+            report_event(Event::Triple { line: index + 1, entries });
+        }
+        if search.is_done() {
+            break;
+        }
+    }
+    Ok(search)
+}
+
+/// A pair or triple found on `line`, as `--stream` prints it: the sum and its product as text,
+/// or a JSON object with the product as a string since it can be too big for a JSON number.
+pub fn found(format: Format, line: usize, entries: &[i64], target: i64) -> String {
+    let product = product(entries);
+    match format {
+        Format::Text => {
+            let sum: Vec<_> = entries.iter().map(ToString::to_string).collect();
+            format!("line {}: {} = {} (product {})", line, sum.join(" + "), target, product)
+        }
+        Format::Json => json!({ "line": line, "entries": entries, "product": product.to_string() }).to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_search() {
+        let report = "1721\n979\n366\n\nnope\n299\n675\n1456\n12\n";
+        let mut events = Vec::new();
+        let search = search(report.as_bytes(), Search::new(2020), |event| events.push(event)).unwrap();
+
+        assert_eq!(vec![
+            Event::Skipped { line: 4, text: "".to_owned() },
+            Event::Skipped { line: 5, text: "nope".to_owned() },
+            Event::Pair { line: 6, entries: [1721, 299] },
+            Event::Triple { line: 7, entries: [366, 979, 675] },
+        ], events);
+        assert_eq!(Some([1721, 299]), search.pair());
+        assert!(search.is_done());
+    }
+
+    #[test]
+    fn test_push() {
+        let mut search = Search::new(2020);
+        assert_eq!((None, None), search.push(1010));
+        assert_eq!((None, None), search.push(5));
+        assert_eq!((Some([1010, 1010]), None), search.push(1010));
+        assert_eq!((None, Some([5, 1010, 1005])), search.push(1005));
+        assert_eq!((None, None), search.push(1015));
+    }
+
+    #[test]
+    fn test_pairs_only() {
+        let report = "1721\n979\n366\n299\n675\n";
+        let mut events = Vec::new();
+        let search = search(report.as_bytes(), Search::pairs_only(2020), |event| events.push(event)).unwrap();
+        assert_eq!(vec![Event::Pair { line: 4, entries: [1721, 299] }], events);
+        assert_eq!(None, search.triple());
+        assert!(search.is_done());
+    }
+
+    #[test]
+    fn test_first_triple() {
+        // 5 makes 10 with both 1 + 4 and 2 + 3.
+        for _ in 0..20 {
+            let mut search = Search::new(10);
+            for entry in [2, 4, 3, 1] {
+                assert_eq!((None, None), search.push(entry));
+            }
+            assert_eq!((None, Some([1, 4, 5])), search.push(5));
+        }
+    }
+
+    #[test]
+    fn test_wide_entries() {
+        let mut search = Search::new(1);
+        search.push(i64::MAX);
+        search.push(i64::MIN);
+        let (_, triple) = search.push(2);
+        assert_eq!(Some([i64::MIN, i64::MAX, 2]), triple);
+        assert_eq!(
+            "line 3: -9223372036854775808 + 9223372036854775807 + 2 = 1 (product -170141183460469231713240559642174554112)",
+            found(Format::Text, 3, &triple.unwrap(), 1),
+        );
+        assert_eq!(
+            r#"{"entries":[-9223372036854775808,9223372036854775807,2],"line":3,"product":"-170141183460469231713240559642174554112"}"#,
+            found(Format::Json, 3, &triple.unwrap(), 1),
+        );
+        assert_eq!((None, None), search.push(i64::MIN + 1));
+        assert_eq!((Some([2, -1]), None), search.push(-1));
+    }
+}
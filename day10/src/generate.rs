@@ -0,0 +1,64 @@
+use aoc_core::generate::Generated;
+use rand::{seq::SliceRandom, Rng};
+
+/// Number of adapters in a generated bag.
+pub const DEFAULT_SIZE: usize = 100;
+
+/// Above this many arrangements, runs of 1-jolt gaps are kept to a single gap so the answer
+/// stays in range.
+const MAX_ARRANGEMENTS: u64 = 1_000_000_000_000_000;
+
+/// A bag of `size` adapters whose joltages differ by 1 or 3 when sorted, with runs of up to
+/// four 1-jolt differences as in the real puzzle.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+    let mut adapters = Vec::with_capacity(size);
+    let mut joltage = 0;
+    let mut arrangements = 1;
+    while adapters.len() < size {
+        let run = if arrangements > MAX_ARRANGEMENTS { 1 } else { rng.gen_range(0..=4) };
+        for _ in 0..run.min(size - adapters.len()) {
+            joltage += 1;
+            adapters.push(joltage);
+        }
+        if adapters.len() < size {
+            joltage += 3;
+            adapters.push(joltage);
+        }
+        arrangements = count_arrangements(&adapters);
+    }
+
+    let ones = (0..adapters.len()).filter(|&i| adapters[i] - if i == 0 { 0 } else { adapters[i - 1] } == 1).count();
+    let threes = adapters.len() - ones + 1;
+    let part2 = count_arrangements(&adapters);
+
+    adapters.shuffle(rng);
+    let input = adapters.iter().map(|adapter| format!("{}\n", adapter)).collect();
+    Generated::new(input, Some((ones * threes).to_string()), Some(part2.to_string()))
+}
+
+/// The number of chains from the outlet to the last of the sorted `adapters`, counted a joltage
+/// at a time.
+fn count_arrangements(adapters: &[u64]) -> u64 {
+    let mut ways = vec![0; adapters.len()];
+    for i in 0..adapters.len() {
+        ways[i] = (adapters[i] <= 3) as u64
+            + (i.saturating_sub(3)..i).filter(|&j| adapters[i] - adapters[j] <= 3).map(|j| ways[j]).sum::<u64>();
+    }
+    ways.last().copied().unwrap_or(1)
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day10;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(8, count_arrangements(&[1, 4, 5, 6, 7, 10, 11, 12, 15, 16, 19]));
+        for seed in 0..10 {
+            generate(&mut StdRng::seed_from_u64(seed), 100).assert_solves::<Day10>();
+        }
+    }
+}
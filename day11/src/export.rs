@@ -0,0 +1,63 @@
+use std::io;
+
+use aoc_automaton::{Automaton, Neighbourhood, Square};
+use aoc_core::Part;
+use aoc_export::{Export, Frames, Rgb, Scene};
+use aoc_grid::Grid;
+
+use crate::{layout, seating, Day11, Seat, RULE, RULE2};
+
+/// Pixels across each spot in the waiting area.
+const SIZE: f64 = 8.0;
+
+const FLOOR: Rgb = Rgb(40, 40, 40);
+const EMPTY: Rgb = Rgb(120, 200, 120);
+const OCCUPIED: Rgb = Rgb(210, 70, 60);
+
+impl Export for Day11 {
+    /// The seat map at the start and after each round, until nobody moves.
+    fn export(ferry: &Grid<Seat>, part: Part, frames: &mut Frames) -> io::Result<()> {
+        let (neighbourhood, rule) = match part {
+            Part::One => (Neighbourhood::Adjacent, RULE),
+            Part::Two => (Neighbourhood::LineOfSight, RULE2),
+        };
+        let mut seating = seating(ferry, neighbourhood, rule);
+        frames.push(seat_map(ferry, &seating))?;
+        while seating.step() {
+            frames.push(seat_map(ferry, &seating))?;
+        }
+        Ok(())
+    }
+}
+
+fn seat_map(ferry: &Grid<Seat>, seating: &Automaton<Square>) -> Scene {
+    let mut scene = Scene::new(ferry.width() * SIZE as usize, ferry.height() * SIZE as usize, FLOOR);
+    scene.grid(&layout(ferry, seating), (0.0, 0.0), SIZE, |seat| match seat {
+        Seat::Floor => None,
+        Seat::Empty => Some(EMPTY),
+        Seat::Occupied => Some(OCCUPIED),
+    });
+    scene
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_export::Format;
+
+    use super::*;
+
+    #[test]
+    fn test_export() {
+        let ferry = Grid::parse("L.L\nLLL").unwrap();
+        let path = std::env::temp_dir().join(format!("day11-export-test-{}.ppm", std::process::id()));
+        let mut frames = Frames::file(&path, Format::Ppm);
+        Day11::export(&ferry, Part::One, &mut frames).unwrap();
+
+        // Everyone sits down in the first round, then the bottom middle seat empties in the second.
+        assert_eq!(3, frames.finish().unwrap());
+        let image = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(&b"P6\n24 16\n255\n"[..], &image[..13]);
+        assert_eq!(24 * 16 * 3, image.len() - 13);
+    }
+}
@@ -0,0 +1,53 @@
+use aoc_automaton::{Neighbourhood, Rule};
+use aoc_core::generate::Generated;
+use aoc_grid::Grid;
+use rand::Rng;
+
+use crate::{seating, Seat, RULE, RULE2};
+
+/// Number of rows in a generated seat layout.
+pub const DEFAULT_SIZE: usize = 90;
+
+/// Rounds a layout gets to settle before it's thrown away.
+const MAX_ROUNDS: usize = 1000;
+
+/// An empty waiting area `size` rows deep and a little wider than it is deep, with floor here
+/// and there between the seats.
+///
+/// Not every layout settles under the part 1 rules, as some patches of seats flip back and forth
+/// forever. Big layouts with little floor hardly ever settle, so there's plenty of floor, and
+/// layouts are drawn until one settles under both rules. The answers only come from running
+/// the seating rules, so they are left to the solver.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+    let size = size.max(1);
+    let width = size + size / 10;
+    loop {
+        let input: String = (0..size)
+            .map(|_| (0..width).map(|_| if rng.gen_bool(0.3) { '.' } else { 'L' }).chain(Some('\n')).collect::<String>())
+            .collect();
+        let layout = Grid::parse(&input).unwrap();
+        if settles(&layout, Neighbourhood::Adjacent, RULE) && settles(&layout, Neighbourhood::LineOfSight, RULE2) {
+            return Generated::new(input, None, None);
+        }
+    }
+}
+
+fn settles(layout: &Grid<Seat>, neighbourhood: Neighbourhood, rule: Rule) -> bool {
+    let mut seating = seating(layout, neighbourhood, rule);
+    (0..MAX_ROUNDS).any(|_| !seating.step())
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day11;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..5 {
+            generate(&mut StdRng::seed_from_u64(seed), 20).assert_solves::<Day11>();
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use aoc_core::{ParseError, Solver};
+use aoc_automaton::{Automaton, Neighbourhood, Rule, Square};
+use aoc_grid::{Cell, Grid};
+
+#[cfg(feature = "export")]
+pub mod export;
+#[cfg(feature = "generate")]
+pub mod generate;
+#[cfg(feature = "repl")]
+pub mod repl;
+
+/// Day 11: Seating System
+pub struct Day11;
+
+impl Solver for Day11 {
+    const DAY: u8 = 11;
+
+    type Input<'a> = Grid<Seat>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        process_input(input)
+    }
+
+    fn part1(ferry: &Self::Input<'_>) -> usize {
+        get_answer(ferry)
+    }
+
+    fn part2(ferry: &Self::Input<'_>) -> usize {
+        get_answer2(ferry)
+    }
+}
+
+fn process_input(input: &str) -> Result<Grid<Seat>, ParseError> {
+    Grid::parse(input)
+}
+
+fn get_answer(ferry: &Grid<Seat>) -> usize {
+    let mut seating = seating(ferry, Neighbourhood::Adjacent, RULE);
+    seating.run_to_fixpoint();
+    seating.count()
+}
+
+fn get_answer2(ferry: &Grid<Seat>) -> usize {
+    let mut seating = seating(ferry, Neighbourhood::LineOfSight, RULE2);
+    seating.run_to_fixpoint();
+    seating.count()
+}
+
+/// Part 1's rule, over the eight neighbouring seats: empty seats fill when none of them are
+/// occupied, and occupied seats empty at four.
+pub const RULE: Rule = Rule::new(&[0], &[0, 1, 2, 3]);
+
+/// Part 2's rule, over the first seat seen in each direction: the same, but seats empty at five.
+pub const RULE2: Rule = Rule::new(&[0], &[0, 1, 2, 3, 4]);
+
+/// The seats of the waiting area as an automaton whose live cells are the occupied seats.
+pub fn seating(ferry: &Grid<Seat>, neighbourhood: Neighbourhood, rule: Rule) -> Automaton<Square> {
+    let seats = Square::new(ferry, |seat| *seat != Seat::Floor, neighbourhood);
+    let occupied = ferry.positions().filter(|&seat| ferry[seat].is_occupied());
+    Automaton::new(seats, rule, occupied)
+}
+
+/// The waiting area with the seats filled as they are in `seating`.
+pub fn layout(ferry: &Grid<Seat>, seating: &Automaton<Square>) -> Grid<Seat> {
+    Grid::from_fn(ferry.width(), ferry.height(), |point| match ferry[point] {
+        Seat::Floor => Seat::Floor,
+        _ if seating.is_live(point) => Seat::Occupied,
+        _ => Seat::Empty,
+    })
+}
+
+/// A spot in the waiting area.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Seat {
+    Empty,
+    Occupied,
+    Floor,
+}
+
+impl Seat {
+    pub fn is_occupied(&self) -> bool {
+        *self == Seat::Occupied
+    }
+}
+
+impl Cell for Seat {
+    const EXPECTED: &'static str = "`L`, `#` or `.`";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'L' => Some(Seat::Empty),
+            '#' => Some(Seat::Occupied),
+            '.' => Some(Seat::Floor),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Seat::Empty => 'L',
+            Seat::Occupied => '#',
+            Seat::Floor => '.',
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "\
+#.##.##.##
+#######.##
+#.#.#..#..
+####.##.##
+#.##.##.##
+#.#####.##
+..#.#.....
+##########
+#.######.#
+#.#####.##";
+
+    #[test]
+    fn test_answer() {
+        let ferry = process_input(INPUT).unwrap();
+        assert_eq!(37, get_answer(&ferry));
+    }
+
+    #[test]
+    fn test_answer2() {
+        let ferry = process_input(INPUT).unwrap();
+        assert_eq!(26, get_answer2(&ferry));
+    }
+}
@@ -0,0 +1,97 @@
+use aoc_automaton::{Automaton, Neighbourhood, Square};
+use aoc_core::repl::{self, Command, Explore, Session};
+use aoc_grid::Grid;
+
+use crate::{layout, seating, Day11, Seat, RULE, RULE2};
+
+const COMMANDS: &[Command] = &[
+    Command { name: "step", args: "[n]", help: "Run n rounds, or 1, and show the seats" },
+    Command { name: "settle", args: "", help: "Run rounds until nobody moves" },
+    Command { name: "show", args: "", help: "Show the seats" },
+    Command { name: "count", args: "", help: "Count the occupied seats" },
+    Command { name: "part", args: "<1|2>", help: "Start again with part 1's or part 2's rules" },
+    Command { name: "reset", args: "", help: "Start again from the input" },
+];
+
+/// The waiting area some rounds in.
+struct WaitingArea {
+    ferry: Grid<Seat>,
+    part2: bool,
+    seating: Automaton<Square>,
+    rounds: usize,
+}
+
+impl WaitingArea {
+    fn reset(&mut self) {
+        self.seating = if self.part2 {
+            seating(&self.ferry, Neighbourhood::LineOfSight, RULE2)
+        } else {
+            seating(&self.ferry, Neighbourhood::Adjacent, RULE)
+        };
+        self.rounds = 0;
+    }
+
+    fn show(&self) -> String {
+        format!("After {} rounds:\n{}", self.rounds, layout(&self.ferry, &self.seating))
+    }
+}
+
+impl Explore for Day11 {
+    fn explore<'a>(ferry: Self::Input<'a>) -> Box<dyn Session + 'a> {
+        let seating = seating(&ferry, Neighbourhood::Adjacent, RULE);
+        Box::new(WaitingArea { ferry, part2: false, seating, rounds: 0 })
+    }
+}
+
+impl Session for WaitingArea {
+    fn commands(&self) -> &'static [Command] {
+        COMMANDS
+    }
+
+    fn run(&mut self, command: &str, args: &str) -> Result<String, String> {
+        match command {
+            "step" => {
+                for _ in 0..repl::count(args)? {
+                    self.seating.step();
+                    self.rounds += 1;
+                }
+                Ok(self.show())
+            }
+            "settle" => {
+                self.rounds += self.seating.run_to_fixpoint();
+                Ok(format!("settled after {} rounds with {} occupied", self.rounds, self.seating.count()))
+            }
+            "show" => Ok(self.show()),
+            "count" => Ok(self.seating.count().to_string()),
+            "part" => {
+                self.part2 = match args {
+                    "1" => false,
+                    "2" => true,
+                    _ => return Err(format!("part must be 1 or 2, not `{}`", args)),
+                };
+                self.reset();
+                Ok(String::new())
+            }
+            "reset" => {
+                self.reset();
+                Ok(String::new())
+            }
+            _ => unreachable!("not one of the commands"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_session() {
+        let mut area = Day11::explore(Grid::parse("L.L\nLLL").unwrap());
+        assert_eq!(Ok("After 1 rounds:\n#.#\n###\n".to_owned()), area.run("step", ""));
+        assert_eq!(Ok("settled after 2 rounds with 4 occupied".to_owned()), area.run("settle", ""));
+        area.run("part", "2").unwrap();
+        assert_eq!(Ok("0".to_owned()), area.run("count", ""));
+        assert!(area.run("part", "3").is_err());
+    }
+}
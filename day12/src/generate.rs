@@ -0,0 +1,61 @@
+use aoc_core::generate::Generated;
+use rand::Rng;
+
+/// Number of instructions in a generated route.
+pub const DEFAULT_SIZE: usize = 780;
+
+/// A route of `size` navigation instructions, with the answers worked out by following it with
+/// east/north vectors.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+    let mut ship = (0i64, 0i64);
+    let mut heading = (1, 0);
+    let mut ship2 = (0i64, 0i64);
+    let mut waypoint = (10, 1);
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let action = *b"NESWLRFFF".get(rng.gen_range(0..9)).unwrap() as char;
+        let value = match action {
+            'L' | 'R' => 90 * rng.gen_range(1..=3),
+            'F' => rng.gen_range(1..=100),
+            _ => rng.gen_range(1..=5),
+        };
+        match action {
+            'L' | 'R' => {
+                let turns = if action == 'L' { value / 90 } else { 4 - value / 90 };
+                for _ in 0..turns {
+                    heading = (-heading.1, heading.0);
+                    waypoint = (-waypoint.1, waypoint.0);
+                }
+            }
+            'F' => {
+                ship = (ship.0 + value * heading.0, ship.1 + value * heading.1);
+                ship2 = (ship2.0 + value * waypoint.0, ship2.1 + value * waypoint.1);
+            }
+            _ => {
+                let (east, north) = match action { 'N' => (0, 1), 'E' => (1, 0), 'S' => (0, -1), _ => (-1, 0) };
+                ship = (ship.0 + value * east, ship.1 + value * north);
+                waypoint = (waypoint.0 + value * east, waypoint.1 + value * north);
+            }
+        }
+        input += &format!("{}{}\n", action, value);
+    }
+
+    let distance = |(east, north): (i64, i64)| (east.abs() + north.abs()).to_string();
+    Generated::new(input, Some(distance(ship)), Some(distance(ship2)))
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day12;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            generate(&mut StdRng::seed_from_u64(seed), 200).assert_solves::<Day12>();
+        }
+    }
+}
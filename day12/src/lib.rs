@@ -0,0 +1,199 @@
+use std::str::FromStr;
+
+use aoc_core::{parse, ParseError, Solver};
+
+#[cfg(feature = "generate")]
+pub mod generate;
+
+/// Day 12: Rain Risk
+pub struct Day12;
+
+impl Solver for Day12 {
+    const DAY: u8 = 12;
+
+    type Input<'a> = Vec<Command>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        process_input(input)
+    }
+
+    fn part1(commands: &Self::Input<'_>) -> i32 {
+        get_answer(commands)
+    }
+
+    fn part2(commands: &Self::Input<'_>) -> i32 {
+        get_answer2(commands)
+    }
+}
+
+fn process_input(input: &str) -> Result<Vec<Command>, ParseError> {
+    parse::lines(input, Command::from_str)
+}
+
+fn get_answer(commands: &Vec<Command>) -> i32 {
+    let mut ferry = Ferry::new();
+    for command in commands {
+        ferry.run_command(*command);
+    }
+    ferry.north.abs() + ferry.east.abs()
+}
+
+fn get_answer2(commands: &Vec<Command>) -> i32 {
+    let mut ferry = Ferry::new();
+    for command in commands {
+        ferry.run_command2(*command);
+    }
+    ferry.north.abs() + ferry.east.abs()
+}
+
+/// A navigation instruction; turns are in degrees.
+#[derive(Clone, Copy, Debug)]
+pub enum Command {
+    North(i32),
+    East(i32),
+    South(i32),
+    West(i32),
+    Forward(i32),
+    Left(i32),
+    Right(i32)
+}
+
+/// Parses an instruction such as `F10` or `R90`.
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let split = s.chars().next().map_or(0, char::len_utf8);
+        let (direction, num_str) = s.split_at(split);
+        let command = match direction {
+            "N" => Command::North,
+            "E" => Command::East,
+            "S" => Command::South,
+            "W" => Command::West,
+            "F" => Command::Forward,
+            "L" => Command::Left,
+            "R" => Command::Right,
+            _ => return Err(ParseError::at(s, direction, "one of `NESWFLR`")),
+        };
+
+        let num = parse::number(s, num_str)?;
+        if (direction == "L" || direction == "R") && num % 90 != 0 {
+            return Err(ParseError::at(s, num_str, "a multiple of 90 degrees"));
+        }
+        Ok(command(num))
+    }
+}
+
+/// The ship and its waypoint, both relative to where the ship started.
+#[derive(Debug)]
+pub struct Ferry {
+    pub north: i32,
+    pub east: i32,
+    /// The way the ship faces, in degrees clockwise from north.
+    pub direction: i32,
+    pub waypoint_north: i32,
+    pub waypoint_east: i32,
+}
+
+impl Default for Ferry {
+    fn default() -> Self {
+        Ferry::new()
+    }
+}
+
+impl Ferry {
+    /// A ship facing east, with its waypoint 10 east and 1 north.
+    pub fn new() -> Self {
+        Ferry {
+            north: 0,
+            east: 0,
+            direction: 90,
+            waypoint_north: 1,
+            waypoint_east: 10,
+        }
+    }
+
+    /// Follows a command the way part 1 reads them, moving the ship itself.
+    pub fn run_command(&mut self, command: Command) {
+        match command {
+            Command::North(num) => self.north += num,
+            Command::East(num) => self.east += num,
+            Command::South(num) => self.north -= num,
+            Command::West(num) => self.east -= num,
+            
+            Command::Forward(num) => match self.direction {
+                0 => self.north += num,
+                90 => self.east += num,
+                180 => self.north -= num,
+                270 => self.east -= num,
+                _ => panic!()
+            }
+            
+            Command::Left(num) => self.direction = (self.direction - num + 360) % 360,
+            Command::Right(num) => self.direction = (self.direction + num) % 360,
+        }
+    }
+
+    /// Follows a command the way part 2 reads them, mostly moving the waypoint.
+    pub fn run_command2(&mut self, command: Command) {
+        match command {
+            Command::North(num) => self.waypoint_north += num,
+            Command::East(num) => self.waypoint_east += num,
+            Command::South(num) => self.waypoint_north -= num,
+            Command::West(num) => self.waypoint_east -= num,
+            
+            Command::Forward(num) => {
+                self.north += num * self.waypoint_north;
+                self.east += num * self.waypoint_east;
+            }
+            
+            Command::Left(num) => self.rotate_waypoint(num),
+            Command::Right(num) => self.rotate_waypoint(-num),
+        }
+    }
+
+    fn rotate_waypoint(&mut self, degrees: i32) {
+        let num = ((360 + degrees) % 360)/90;
+        for _ in 0..num {
+            let temp = self.waypoint_north;
+            self.waypoint_north = self.waypoint_east;
+            self.waypoint_east = - temp;
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "\
+F10
+N3
+F7
+R90
+F11";
+
+    #[test]
+    fn test_answer() {
+        let commands = process_input(INPUT).unwrap();
+        assert_eq!(25, get_answer(&commands));
+    }
+
+    #[test]
+    fn test_answer2() {
+        let commands = process_input(INPUT).unwrap();
+        assert_eq!(286, get_answer2(&commands));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = process_input("F10\nX3").unwrap_err();
+        assert_eq!(ParseError::new(1, "one of `NESWFLR`", "X").on_line(2), error);
+
+        let error = process_input("F10\nN3\nR45").unwrap_err();
+        assert_eq!(ParseError::new(2, "a multiple of 90 degrees", "45").on_line(3), error);
+    }
+}
\ No newline at end of file
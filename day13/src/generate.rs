@@ -0,0 +1,76 @@
+use aoc_core::generate::Generated;
+use rand::{seq::SliceRandom, Rng};
+
+/// Number of buses in a generated schedule.
+pub const DEFAULT_SIZE: usize = 9;
+
+/// Part 2 sums a term per bus, each up to the largest bus times the product of all of them, in
+/// an `i64`.
+const MAX_PRODUCT: i128 = i64::MAX as i128;
+
+/// A timestamp and a schedule of `size` buses with distinct prime IDs, each no further along the
+/// schedule than its own ID, as in the real puzzle.
+///
+/// `size` is capped at 10 buses to keep part 2's answer in range.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+    let primes: Vec<i64> = (7..1000).filter(|&n: &i64| (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0)).collect();
+    let count = size.clamp(1, 10);
+    // Each bus is drawn from the primes that still leave room for the smallest ones after it.
+    let mut buses: Vec<i64> = Vec::with_capacity(count);
+    for slot in 0..count {
+        let fits: Vec<i64> = primes.iter().copied().filter(|bus| !buses.contains(bus)).filter(|&bus| {
+            let rest = primes.iter().copied().filter(|other| *other != bus && !buses.contains(other)).take(count - slot - 1);
+            let all: Vec<i128> = buses.iter().copied().chain(Some(bus)).chain(rest).map(i128::from).collect();
+            all.iter().product::<i128>() * all.iter().max().unwrap() * count as i128 <= MAX_PRODUCT
+        }).collect();
+        buses.push(*fits.choose(rng).unwrap());
+    }
+    buses.shuffle(rng);
+
+    let mut offsets = vec![0];
+    for &bus in &buses[1..] {
+        let free: Vec<i64> = (1..bus).filter(|offset| !offsets.contains(offset)).collect();
+        offsets.push(*free.choose(rng).unwrap());
+    }
+    let mut schedule = vec!["x".to_owned(); *offsets.iter().max().unwrap() as usize + 1];
+    for (&bus, &offset) in buses.iter().zip(&offsets) {
+        schedule[offset as usize] = bus.to_string();
+    }
+
+    // Avoid timestamps on which a bus leaves, or two buses tie for the shortest wait.
+    let (timestamp, part1) = loop {
+        let timestamp = rng.gen_range(100_000..10_000_000);
+        let mut waits: Vec<(i64, i64)> = buses.iter().map(|&bus| (bus - timestamp % bus, bus)).collect();
+        waits.sort();
+        if waits.iter().all(|&(wait, bus)| wait < bus) && (waits.len() == 1 || waits[0].0 < waits[1].0) {
+            break (timestamp, waits[0].0 * waits[0].1);
+        }
+    };
+
+    // Sieve for the earliest time, fixing one bus at a time.
+    let (mut time, mut step) = (0i128, 1i128);
+    for (&bus, &offset) in buses.iter().zip(&offsets) {
+        while (time + offset as i128) % bus as i128 != 0 {
+            time += step;
+        }
+        step *= bus as i128;
+    }
+
+    let input = format!("{}\n{}\n", timestamp, schedule.join(","));
+    Generated::new(input, Some(part1.to_string()), Some(time.to_string()))
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day13;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..20 {
+            generate(&mut StdRng::seed_from_u64(seed), 9).assert_solves::<Day13>();
+        }
+    }
+}
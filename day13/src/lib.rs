@@ -0,0 +1,89 @@
+use aoc_core::{ParseError, Solver};
+use aoc_math::chinese_remainder;
+use aoc_nom::{comma_list, context, unsigned, IResult};
+use nom::{
+    branch::alt,
+    character::complete::{char, line_ending},
+    combinator::{map, value},
+    sequence::separated_pair,
+};
+
+#[cfg(feature = "generate")]
+pub mod generate;
+
+/// Day 13: Shuttle Search
+pub struct Day13;
+
+impl Solver for Day13 {
+    const DAY: u8 = 13;
+
+    type Input<'a> = (i64, Vec<Option<i64>>);
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        process_input(input)
+    }
+
+    fn part1((timestamp, busses): &Self::Input<'_>) -> i64 {
+        get_answer(*timestamp, busses)
+    }
+
+    fn part2((_timestamp, busses): &Self::Input<'_>) -> i64 {
+        get_answer2(busses)
+    }
+}
+
+fn process_input(input: &str) -> Result<(i64, Vec<Option<i64>>), ParseError> {
+    aoc_nom::finish(input, separated_pair(unsigned, line_ending, comma_list(parse_bus)))
+}
+
+fn parse_bus(i: &str) -> IResult<'_, Option<i64>> {
+    context("a bus id or `x`", alt((
+        map(unsigned, Some),
+        value(None, char('x')),
+    )))(i)
+}
+
+fn get_answer(timestamp: i64, busses: &[Option<i64>]) -> i64 {
+    let busses: Vec<_> = busses.iter().filter_map(|a| a.as_ref()).collect();
+    let times_since_last = busses.iter().map(|b| timestamp % *b);
+    let times_until_next = times_since_last.enumerate().map(|(index, last)| busses[index] - last);
+    let (bus_index, next) = times_until_next.enumerate().min_by_key(|(_, next)| *next).unwrap();
+    next * busses[bus_index]
+}
+
+fn get_answer2(busses: &[Option<i64>]) -> i64 {
+    let offsets_and_busses: Vec<_> = busses.iter().enumerate()
+        .filter_map(|(offset, bus)| bus.map(|b| (offset as i128, b as i128))).collect();
+    let busses: Vec<_> = offsets_and_busses.iter().map(|(_, b)| *b).collect();
+    let offsets: Vec<_> = offsets_and_busses.iter().map(|(o, b)| b - o).collect();
+    chinese_remainder(&offsets, &busses).unwrap() as i64
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "\
+939
+7,13,x,x,59,x,31,19";
+
+    #[test]
+    fn test_answer() {
+        let (timestamp, busses) = process_input(INPUT).unwrap();
+        assert_eq!(295, get_answer(timestamp, &busses));
+    }
+
+    #[test]
+    fn test_answer2() {
+        let (_, busses) = process_input(INPUT).unwrap();
+        assert_eq!(1068781, get_answer2(&busses));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = process_input("939\n7,13,y,59").err();
+        assert_eq!(Some(ParseError::new(6, "a bus id or `x`", "y").on_line(2)), error);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+
+use aoc_core::generate::Generated;
+use rand::{seq::index, Rng};
+
+/// Number of masks in a generated initialization program.
+pub const DEFAULT_SIZE: usize = 100;
+
+/// A program of `size` masks, each followed by a few writes to addresses below 65536.
+///
+/// Every mask has four to nine floating bits, as in the real puzzle, so a version 2 write never
+/// touches more than 512 addresses.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+    let mut memory = HashMap::new();
+    let mut memory2 = HashMap::new();
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let count = rng.gen_range(4..=9);
+        let floating: Vec<usize> = index::sample(rng, 36, count).into_vec();
+        let bits: Vec<char> = (0..36)
+            .map(|bit| if floating.contains(&bit) { 'X' } else if rng.gen_bool(0.5) { '1' } else { '0' })
+            .collect();
+        input += &format!("mask = {}\n", bits.iter().collect::<String>());
+
+        // Bit 35 of the value is the first character of the mask.
+        let bit = |value: u64, bit: usize| value >> (35 - bit) & 1;
+        for _ in 0..rng.gen_range(1..=6) {
+            let address: u64 = rng.gen_range(0..65536);
+            let value: u64 = rng.gen_range(0..1 << 36);
+            input += &format!("mem[{}] = {}\n", address, value);
+
+            let masked = (0..36).fold(0, |masked, i| masked << 1 | match bits[i] {
+                'X' => bit(value, i),
+                c => c.to_digit(2).unwrap() as u64,
+            });
+            memory.insert(address, masked);
+
+            for choice in 0..1u64 << floating.len() {
+                let decoded = (0..36).fold(0, |decoded, i| decoded << 1 | match bits[i] {
+                    'X' => choice >> floating.iter().position(|&at| at == i).unwrap() & 1,
+                    '1' => 1,
+                    _ => bit(address, i),
+                });
+                memory2.insert(decoded, value);
+            }
+        }
+    }
+
+    let part1 = memory.values().sum::<u64>();
+    let part2 = memory2.values().sum::<u64>();
+    Generated::new(input, Some(part1.to_string()), Some(part2.to_string()))
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day14;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            generate(&mut StdRng::seed_from_u64(seed), 20).assert_solves::<Day14>();
+        }
+    }
+}
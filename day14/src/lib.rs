@@ -0,0 +1,253 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use aoc_core::{parse, ParseError, Solver};
+
+#[cfg(feature = "generate")]
+pub mod generate;
+
+/// Day 14: Docking Data
+pub struct Day14;
+
+impl Solver for Day14 {
+    const DAY: u8 = 14;
+
+    type Input<'a> = Vec<Line>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        process_input(input)
+    }
+
+    fn part1(lines: &Self::Input<'_>) -> u64 {
+        get_answer(lines)
+    }
+
+    fn part2(lines: &Self::Input<'_>) -> u64 {
+        get_answer2(lines)
+    }
+}
+
+fn process_input(input: &str) -> Result<Vec<Line>, ParseError> {
+    parse::lines(input, |s| {
+        if s.starts_with("mask") { return Mask::from_str(s).map(Line::Mask)}
+        if s.starts_with("mem") { return Command::from_str(s).map(Line::Command)}
+        let word = s.split(|c: char| !c.is_ascii_alphabetic()).next().unwrap_or("");
+        Err(ParseError::at(s, word, "`mask` or `mem`"))
+    })
+}
+
+fn get_answer(lines: &Vec<Line>) -> u64 {
+    let mut memory: HashMap<usize, u64> = HashMap::new();
+    let mut mask = Mask::default();
+
+    for line in lines {
+        match line {
+            Line::Mask(m) => mask = m.clone(),
+            Line::Command(command) => {
+                memory.insert(command.index, mask.mask(command.value));
+            }
+        }
+    }
+
+    memory.values().sum()
+}
+
+fn get_answer2(lines: &Vec<Line>) -> u64 {
+    let mut memory: HashMap<usize, u64>  = HashMap::new();
+    let mut mask = Mask::default();
+
+    for line in lines {
+        match line {
+            Line::Mask(m) => mask = m.clone(),
+            Line::Command(command) => {
+                for index in mask.indexes(command.index) {
+                    memory.insert(index, command.value);
+                }
+            }
+        }
+    }
+
+    memory.values().sum()
+}
+
+/// One line of the initialization program.
+pub enum Line {
+    Mask(Mask),
+    Command(Command),
+}
+
+/// A 36-bit bitmask such as `mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X`.
+#[derive(Clone, Default, Debug)]
+pub struct Mask {
+    /// Clear where the mask has a `0`.
+    zeros: u64,
+    /// Set where the mask has a `1`.
+    ones: u64,
+}
+
+/// Parses a `mask = ...` line.
+impl FromStr for Mask {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mask = input.strip_prefix("mask = ")
+            .ok_or_else(|| ParseError::at(input, input, "`mask = `"))?;
+        if mask.len() != 36 {
+            return Err(ParseError::at(input, mask, "36 mask bits"));
+        }
+
+        let mut zeros = u64::MAX;
+        let mut ones = 0u64;
+        for (index, character) in mask.char_indices() {
+            zeros = zeros.rotate_left(1);
+            ones = ones.rotate_left(1);
+            match character {
+                '0' => zeros -= 1,
+                '1' => ones += 1,
+                'X' => {}
+                _ => {
+                    let found = &mask[index..index + character.len_utf8()];
+                    return Err(ParseError::at(input, found, "`0`, `1` or `X`"));
+                }
+            }
+        }
+        Ok(Mask { zeros, ones })
+    }
+}
+
+impl Mask {
+    /// Applies the mask to a value the version 1 way: `0` and `1` overwrite, `X` keeps.
+    pub fn mask(&self, num: u64) -> u64 {
+        (num & self.zeros) | self.ones
+    }
+
+    /// Every address the version 2 decoder writes to for `command_index`, with each `X` taking
+    /// both values.
+    pub fn indexes(&self, command_index: usize) -> MaskIter {
+        let floating =  (self.zeros & (!self.ones)) % (2u64.pow(36));
+        let command_index = (command_index | self.ones as usize) & !floating as usize;
+        MaskIter {command_index, floating, internal_index: 0}
+    }
+}
+
+/// The addresses picked out by a mask's floating bits; see [`Mask::indexes`].
+pub struct MaskIter {
+    command_index: usize,
+    floating: u64,
+    internal_index: usize,
+}
+
+impl Iterator for MaskIter {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut index = 0;
+        let mut shift = 0;
+        let digits = 64 - self.internal_index.leading_zeros();
+        if digits > self.floating.count_ones() { return None; }
+
+        for ii_shift in 0..digits {
+            let current_digit = (self.internal_index >> ii_shift) & 1;
+            while ((self.floating >> shift) & 1) == 0 {
+                shift += 1;
+            }
+            index += current_digit << shift;
+            shift += 1;
+        }
+        
+        self.internal_index += 1;
+        Some(self.command_index | index)
+    }
+}
+
+/// The largest address or value, as both are 36 bits wide.
+const MAX_36_BIT: u64 = (1 << 36) - 1;
+
+/// A write to memory, e.g. `mem[8] = 11`.
+pub struct Command {
+    pub index: usize,
+    pub value: u64,
+}
+
+/// Parses a `mem[...] = ...` line.
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let rest = input.strip_prefix("mem[")
+            .ok_or_else(|| ParseError::at(input, input, "`mem[`"))?;
+        let (index, value) = rest.split_once("] = ")
+            .ok_or_else(|| ParseError::at(input, rest, "an address followed by `] = `"))?;
+        let index_number: u64 = parse::number(input, index)?;
+        let value_number: u64 = parse::number(input, value)?;
+        if index_number > MAX_36_BIT {
+            return Err(ParseError::at(input, index, "a 36-bit address"));
+        }
+        if value_number > MAX_36_BIT {
+            return Err(ParseError::at(input, value, "a 36-bit value"));
+        }
+        Ok(Command { index: index_number as usize, value: value_number })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "\
+mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X
+mem[8] = 11
+mem[7] = 101
+mem[8] = 0";
+
+    #[test]
+    fn test_answer() {
+        let lines = process_input(INPUT).unwrap();
+        assert_eq!(165, get_answer(&lines));
+    }
+
+    const INPUT2: &str = "\
+mask = 000000000000000000000000000000X1001X
+mem[42] = 100
+mask = 00000000000000000000000000000000X0XX
+mem[26] = 1";
+
+    #[test]
+    fn test_answer2() {
+        let lines = process_input(INPUT2).unwrap();
+        assert_eq!(208, get_answer2(&lines));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = process_input("mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX2X").err();
+        assert_eq!(Some(ParseError::new(42, "`0`, `1` or `X`", "2")), error);
+
+        let error = process_input("mask = XX\nmem[8] = 11").err();
+        assert_eq!(Some(ParseError::new(8, "36 mask bits", "XX")), error);
+
+        let error = process_input("mem[8] = 11\nmem[x] = 101").err();
+        assert_eq!(Some(ParseError::new(5, "a number", "x").on_line(2)), error);
+
+        let error = process_input("mem[8] = 11\nmem[9]").err();
+        assert_eq!(Some(ParseError::new(5, "an address followed by `] = `", "9]").on_line(2)), error);
+
+        let error = process_input("mov[8] = 11").err();
+        assert_eq!(Some(ParseError::new(1, "`mask` or `mem`", "mov")), error);
+    }
+
+    #[test]
+    fn test_wide_numbers() {
+        // Found by fuzzing: part 1 used to allocate memory right up to the highest address.
+        let error = process_input("mem[087469490945445077] = 4504\nmem[00000909454450778] = 11").err();
+        assert_eq!(Some(ParseError::new(5, "a 36-bit address", "087469490945445077")), error);
+
+        let error = process_input("mem[8] = 68719476736").err();
+        assert_eq!(Some(ParseError::new(10, "a 36-bit value", "68719476736")), error);
+
+        let lines = process_input(&format!("mask = {}\nmem[68719476735] = 68719476735", "X".repeat(36))).unwrap();
+        assert_eq!(68719476735, get_answer(&lines));
+    }
+}
@@ -0,0 +1,43 @@
+use aoc_core::generate::Generated;
+use rand::{seq::index, Rng};
+
+/// Number of starting numbers in a generated game.
+pub const DEFAULT_SIZE: usize = 6;
+
+/// A game opening with `size` different numbers below 20.
+///
+/// Part 1 is worked out by playing the 2020 turns; part 2 is left to the solver, as it takes 30
+/// million of them.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+    let starting = index::sample(rng, 20, size.clamp(1, 20)).into_vec();
+
+    // The turn each number was last spoken on, not counting the most recent turn.
+    let mut last_spoken = vec![None; 2020];
+    let mut spoken = starting[0];
+    for turn in 1..2020 {
+        let next = match starting.get(turn) {
+            Some(&number) => number,
+            None => last_spoken[spoken].map_or(0, |last| turn - 1 - last),
+        };
+        last_spoken[spoken] = Some(turn - 1);
+        spoken = next;
+    }
+
+    let input = starting.iter().map(usize::to_string).collect::<Vec<_>>().join(",") + "\n";
+    Generated::new(input, Some(spoken.to_string()), None)
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day15;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            generate(&mut StdRng::seed_from_u64(seed), 6).assert_solves::<Day15>();
+        }
+    }
+}
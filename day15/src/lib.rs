@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+
+use aoc_core::{parse, ParseError, Solver};
+
+#[cfg(feature = "generate")]
+pub mod generate;
+
+/// Day 15: Rambunctious Recitation
+pub struct Day15;
+
+impl Solver for Day15 {
+    const DAY: u8 = 15;
+    const DEFAULT_INPUT: Option<&'static str> = Some("1,20,8,12,0,14");
+
+    type Input<'a> = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        process_input(input)
+    }
+
+    fn part1(lines: &Self::Input<'_>) -> usize {
+        get_answer(lines)
+    }
+
+    fn part2(lines: &Self::Input<'_>) -> usize {
+        get_answer2(lines)
+    }
+}
+
+fn process_input(input: &str) -> Result<Vec<usize>, ParseError> {
+    let input = input.trim();
+    input.split(',').map(|s| parse::number(input, s)).collect()
+}
+
+fn get_answer(lines: &Vec<usize>) -> usize {
+    Game::new(lines).nth(2020 - 1).unwrap()
+}
+
+fn get_answer2(lines: &Vec<usize>) -> usize {
+    Game::new(lines).nth(30000000 - 1).unwrap()
+}
+
+/// The elves' memory game, as an endless iterator over the numbers spoken.
+pub struct Game<I: Iterator> {
+    iteration: usize,
+    starting: std::iter::Fuse<I>,
+    spoken: HashMap<usize, usize>,
+    next_num: Option<usize>,
+}
+
+impl<'a, I: Iterator<Item=&'a usize>> Game<I> {
+    /// Starts a game that opens with `iter`'s numbers.
+    pub fn new<T: IntoIterator<IntoIter=I, Item=&'a usize>>(iter: T) -> Self {
+        Game {
+            iteration: 0,
+            starting: iter.into_iter().fuse(),
+            spoken: HashMap::new(),
+            next_num: None,
+        }
+    }
+}
+
+impl<'a, I: Iterator<Item=&'a usize>> Iterator for Game<I> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let num = if let Some(num) = self.starting.next() {
+            *num
+        } else {
+            if let Some(last_iteration) = self.next_num {
+                 self.iteration - last_iteration -1 // we've already incremented
+            } else {
+                0
+            }
+        };
+
+        self.next_num = self.spoken.insert(num, self.iteration);
+
+        self.iteration += 1;
+        Some(num)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "0,3,6";
+
+    #[test]
+    fn test_answer() {
+        let lines = process_input(INPUT).unwrap();
+        assert_eq!(436, get_answer(&lines));
+    }
+
+    const INPUT2: &str = "0,3,6";
+
+    #[test]
+    fn test_answer2() {
+        let lines = process_input(INPUT2).unwrap();
+        assert_eq!(175594, get_answer2(&lines));
+    }
+}
@@ -0,0 +1,88 @@
+use aoc_core::generate::Generated;
+use rand::{seq::SliceRandom, Rng};
+
+/// Number of nearby tickets in generated notes.
+pub const DEFAULT_SIZE: usize = 240;
+
+const FIELDS: [&str; 20] = [
+    "departure location", "departure station", "departure platform", "departure track",
+    "departure date", "departure time", "arrival location", "arrival station", "arrival platform",
+    "arrival track", "class", "duration", "price", "route", "row", "seat", "train", "type", "wagon",
+    "zone",
+];
+
+/// Width of the band of values each column draws from.
+const BAND: u32 = 45;
+
+/// Notes with the real puzzle's 20 fields and `size` nearby tickets, about a quarter of them with
+/// a value no field allows.
+///
+/// The fields are ranked, and the column for the field of rank `k` only holds values from band
+/// `k`. Each field's rule allows every band from its own rank up, less one value, so the column of
+/// rank 0 fits one field, the column of rank 1 fits two, and so on, which pins down a unique
+/// assignment of fields to columns.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+    let n = FIELDS.len() as u32;
+    let top = BAND * n;
+
+    let mut fields = FIELDS;
+    fields.shuffle(rng);
+    let gaps: Vec<u32> = (0..n).map(|rank| rng.gen_range(BAND * rank + 2..top)).collect();
+    let mut rules: Vec<String> = (0..n as usize).map(|rank| {
+        format!("{}: {}-{} or {}-{}", fields[rank], BAND * rank as u32 + 1, gaps[rank] - 1, gaps[rank] + 1, top)
+    }).collect();
+    rules.shuffle(rng);
+
+    // The rank of the field in each column.
+    let mut ranks: Vec<u32> = (0..n).collect();
+    ranks.shuffle(rng);
+    let ticket = |rng: &mut R| -> Vec<u32> {
+        ranks.iter().map(|&rank| loop {
+            let value = rng.gen_range(BAND * rank + 1..=BAND * (rank + 1));
+            if !gaps.contains(&value) {
+                break value;
+            }
+        }).collect()
+    };
+
+    let yours = ticket(rng);
+    let mut invalid = 0;
+    let nearby: Vec<Vec<u32>> = (0..size).map(|_| {
+        let mut nearby = ticket(rng);
+        if rng.gen_bool(0.25) {
+            let value = rng.gen_range(top + 1..1000);
+            nearby[rng.gen_range(0..n as usize)] = value;
+            invalid += value;
+        }
+        nearby
+    }).collect();
+
+    let departures = ranks.iter().zip(&yours)
+        .filter(|(&rank, _)| fields[rank as usize].starts_with("departure"))
+        .map(|(_, &value)| value as i64)
+        .product::<i64>();
+
+    let list = |ticket: &Vec<u32>| ticket.iter().map(u32::to_string).collect::<Vec<_>>().join(",");
+    let input = format!(
+        "{}\n\nyour ticket:\n{}\n\nnearby tickets:\n{}\n",
+        rules.join("\n"),
+        list(&yours),
+        nearby.iter().map(list).collect::<Vec<_>>().join("\n"),
+    );
+    Generated::new(input, Some(invalid.to_string()), Some(departures.to_string()))
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day16;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            generate(&mut StdRng::seed_from_u64(seed), 50).assert_solves::<Day16>();
+        }
+    }
+}
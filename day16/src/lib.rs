@@ -0,0 +1,223 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::{ParseError, Solver};
+use aoc_nom::{blank_line, comma_list, context, lines, section, tag, unsigned, IResult};
+use nom::{
+    bytes::complete::take_till1,
+    character::complete::char,
+    combinator::map,
+    multi::separated_list1,
+    sequence::{separated_pair, tuple},
+};
+
+#[cfg(feature = "generate")]
+pub mod generate;
+
+/// Day 16: Ticket Translation
+pub struct Day16;
+
+impl Solver for Day16 {
+    const DAY: u8 = 16;
+
+    type Input<'a> = Notes;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        process_input(input)
+    }
+
+    fn part1((fields, _your_ticket, nearby_tickets): &Self::Input<'_>) -> i64 {
+        get_answer(fields, nearby_tickets)
+    }
+
+    fn part2((fields, your_ticket, nearby_tickets): &Self::Input<'_>) -> i64 {
+        get_answer2(fields, your_ticket, nearby_tickets)
+    }
+}
+
+/// The rules, your ticket and the nearby tickets.
+pub type Notes = (RuleSet, Vec<i64>, Vec<Vec<i64>>);
+
+fn process_input(input: &str) -> Result<Notes, ParseError> {
+    let ruleset = map(lines(parse_rule), |rules| RuleSet(rules.into_iter().collect()));
+    let your_ticket = section("your ticket:", parse_ticket);
+    let nearby_tickets = section("nearby tickets:", lines(parse_ticket));
+
+    let notes = tuple((ruleset, blank_line, your_ticket, blank_line, nearby_tickets));
+    aoc_nom::finish(input, map(notes, |(ruleset, _, your_ticket, _, nearby_tickets)| {
+        (ruleset, your_ticket, nearby_tickets)
+    }))
+}
+
+fn parse_rule(i: &str) -> IResult<'_, (String, Rule)> {
+    let name = context("a field name", take_till1(|c| c == ':' || c == '\n'));
+    let range = separated_pair(unsigned, char('-'), unsigned);
+    let rule = map(separated_list1(tag(" or "), range), Rule);
+    map(separated_pair(name, tag(": "), rule), |(name, rule)| (name.to_owned(), rule))(i)
+}
+
+fn parse_ticket(i: &str) -> IResult<'_, Vec<i64>> {
+    comma_list(unsigned)(i)
+}
+
+fn get_answer(ruleset: &RuleSet, nearby_tickets: &[Vec<i64>]) -> i64 {
+    let mut count = 0;
+    for ticket in nearby_tickets {
+        count += ticket.iter().filter(|value| {
+            !ruleset.validate(**value)
+        }).sum::<i64>();
+    }
+    count
+}
+
+fn get_answer2(ruleset: &RuleSet, your_ticket: &[i64], nearby_tickets: &[Vec<i64>]) -> i64 {
+    let field_positions = field_positions(ruleset, nearby_tickets);
+
+    let location = field_positions.get("departure location").unwrap();
+    let station = field_positions.get("departure station").unwrap();
+    let platform = field_positions.get("departure platform").unwrap();
+    let track = field_positions.get("departure track").unwrap();
+    let date = field_positions.get("departure date").unwrap();
+    let time = field_positions.get("departure time").unwrap();
+
+    your_ticket[*location] *
+    your_ticket[*station] *
+    your_ticket[*platform] *
+    your_ticket[*track] *
+    your_ticket[*date] *
+    your_ticket[*time]
+}
+
+/// Which position on a ticket each field is at, worked out from the valid nearby tickets.
+fn field_positions(ruleset: &RuleSet, nearby_tickets: &[Vec<i64>]) -> HashMap<String, usize> {
+    let nearby_tickets: Vec<_> = nearby_tickets.iter().filter(|ticket| {
+        !ticket.iter().any(|value| {
+            !ruleset.validate(*value)
+        })
+    }).collect();
+
+    let num_fields = nearby_tickets[0].len();
+
+    let mut rule_indexes: Vec<_> = (0..num_fields).map( |field_number| {
+        let mut possible_rules: HashSet<_> = ruleset.0.iter().collect();
+        for ticket in nearby_tickets.iter() {
+            possible_rules.retain(|(_, rule)| {
+                ruleset.matching_rules(ticket[field_number])
+                    .any(|matching_rule| matching_rule.1 == *rule)
+            });
+        }
+        (field_number, possible_rules)
+    }).collect();
+
+    rule_indexes.sort_by_key(|rules| rules.1.len());
+
+    for index in 1..num_fields  {
+        let (good_rulesets, bad_rulesets) = rule_indexes.split_at_mut(index);
+
+        let good_rule_name = good_rulesets.last().unwrap().1.iter().next().unwrap().0;
+        for rule_set in bad_rulesets {
+            rule_set.1.retain(|rule| rule.0 != good_rule_name);
+        }
+    }
+
+    rule_indexes.iter().map(|(rule_number, rules)| {
+        assert_eq!(1, rules.len());
+        let rule = rules.iter().next().unwrap();
+        (rule.0.clone(), *rule_number)
+    }).collect()
+}
+
+/// The ranges a ticket field's value may fall in, e.g. `1-3 or 5-7`.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Rule(pub Vec<(i64, i64)>);
+
+impl Rule {
+    /// Whether `value` is in any of the (inclusive) ranges.
+    pub fn validate(&self, value: i64) -> bool {
+        self.0.iter().any(|(min, max)| {
+            *min <= value && value <= *max
+        })
+    }
+}
+
+/// Every ticket field's rule, by field name.
+pub struct RuleSet(pub HashMap<String, Rule>);
+
+impl RuleSet {
+    /// Whether `value` would be valid for any field at all.
+    pub fn validate(&self, value: i64) -> bool {
+        self.0.values().any(|rule| {
+            rule.validate(value)
+        })
+    }
+
+    /// The fields `value` would be valid for.
+    pub fn matching_rules(&self, value: i64) -> impl Iterator<Item=(&String, &Rule)> {
+        self.0.iter().filter(move |(_, rule)|{
+            rule.validate(value)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "\
+class: 1-3 or 5-7
+row: 6-11 or 33-44
+seat: 13-40 or 45-50
+
+your ticket:
+7,1,14
+
+nearby tickets:
+7,3,47
+40,4,50
+55,2,20
+38,6,12";
+
+    #[test]
+    fn test_answer() {
+        let (fields, _your_ticket, nearby_tickets) = process_input(INPUT).unwrap();
+        assert_eq!(71, get_answer(&fields, &nearby_tickets));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = process_input(&INPUT.replace("6-11", "6..11")).err();
+        assert_eq!(Some(ParseError::new(7, "`-`", ".").on_line(2)), error);
+
+        let error = process_input(&INPUT.replace("40,4,50", "40,four,50")).err();
+        assert_eq!(Some(ParseError::new(4, "a number", "four").on_line(10)), error);
+
+        let error = process_input(&INPUT.replace("your ticket", "my ticket")).err();
+        assert_eq!(Some(ParseError::new(1, "`your ticket:`", "my").on_line(5)), error);
+
+        let error = process_input("class: 1-3 or 5-7").err();
+        assert_eq!(Some(ParseError::new(18, "a blank line", "")), error);
+    }
+
+    const INPUT2: &str = "\
+class: 0-1 or 4-19
+row: 0-5 or 8-19
+seat: 0-13 or 16-19
+
+your ticket:
+11,12,13
+
+nearby tickets:
+3,9,18
+15,1,5
+5,14,9";
+
+    #[test]
+    fn test_field_positions() {
+        let (fields, _your_ticket, nearby_tickets) = process_input(INPUT2).unwrap();
+        let field_positions = field_positions(&fields, &nearby_tickets);
+        assert_eq!(Some(&1), field_positions.get("class"));
+        assert_eq!(Some(&0), field_positions.get("row"));
+        assert_eq!(Some(&2), field_positions.get("seat"));
+    }
+}
@@ -0,0 +1,93 @@
+use std::io;
+
+use aoc_automaton::{Automaton, Lattice};
+use aoc_core::Part;
+use aoc_export::{Export, Frames, Rgb, Scene};
+use aoc_grid::Grid;
+
+use crate::{pocket_dimension, Day17, CYCLES};
+
+/// Pixels across each cube.
+const SIZE: f64 = 4.0;
+
+const SPACE: Rgb = Rgb(10, 10, 30);
+const LAYER: Rgb = Rgb(40, 40, 70);
+const ACTIVE: Rgb = Rgb(250, 200, 60);
+
+impl Export for Day17 {
+    /// The active cubes at the start and after each cycle, with a layer for each `z` from left to
+    /// right, and in part 2 a row of them for each `w` from top to bottom.
+    fn export(slice: &Grid<bool>, part: Part, frames: &mut Frames) -> io::Result<()> {
+        match part {
+            Part::One => boot::<3>(slice, frames),
+            Part::Two => boot::<4>(slice, frames),
+        }
+    }
+}
+
+fn boot<const N: usize>(slice: &Grid<bool>, frames: &mut Frames) -> io::Result<()> {
+    let mut cubes = pocket_dimension::<N>(slice);
+    frames.push(draw(slice, &cubes))?;
+    for _ in 0..CYCLES {
+        cubes.step();
+        frames.push(draw(slice, &cubes))?;
+    }
+    Ok(())
+}
+
+/// Draws every layer the cubes can have spread to by the last cycle, so that each frame is the
+/// same size.
+fn draw<const N: usize>(slice: &Grid<bool>, cubes: &Automaton<Lattice<N>>) -> Scene {
+    // Cubes spread at most one step along each axis per cycle.
+    let reach = CYCLES as i32;
+    let layer = (slice.width() as i32 + 2 * reach, slice.height() as i32 + 2 * reach);
+    let zs = -reach..=reach;
+    let ws = if N > 3 { -reach..=reach } else { 0..=0 };
+
+    // A cube's gap around every layer.
+    let across = |cells: i32, layers: usize| ((cells + 1) * layers as i32 + 1) as usize * SIZE as usize;
+    let mut scene = Scene::new(across(layer.0, zs.clone().count()), across(layer.1, ws.clone().count()), SPACE);
+    let corner = |z: i32, w: i32| {
+        let x = (z + reach) * (layer.0 + 1) + 1;
+        let y = (w - ws.start()) * (layer.1 + 1) + 1;
+        (x as f64 * SIZE, y as f64 * SIZE)
+    };
+
+    for z in zs {
+        for w in ws.clone() {
+            scene.rect(corner(z, w), (layer.0 as f64 * SIZE, layer.1 as f64 * SIZE), LAYER);
+        }
+    }
+    for cube in cubes.live() {
+        let (x, y) = corner(cube[2], cube.get(3).copied().unwrap_or(0));
+        let offset = ((cube[0] + reach) as f64 * SIZE, (cube[1] + reach) as f64 * SIZE);
+        scene.rect((x + offset.0, y + offset.1), (SIZE, SIZE), ACTIVE);
+    }
+    scene
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_export::{Format, Shape};
+
+    use super::*;
+
+    #[test]
+    fn test_draw() {
+        let slice = Grid::parse(".#.\n..#\n###").unwrap();
+        let active = |scene: &Scene| scene.shapes().iter()
+            .filter(|shape| matches!(shape, Shape::Rect { colour: ACTIVE, .. }))
+            .count();
+
+        let scene = draw(&slice, &pocket_dimension::<3>(&slice));
+        assert_eq!((836, 68), (scene.width(), scene.height()));
+        assert_eq!(5, active(&scene));
+        let scene = draw(&slice, &pocket_dimension::<4>(&slice));
+        assert_eq!((836, 836), (scene.width(), scene.height()));
+
+        // Nothing is written without `finish`.
+        let mut frames = Frames::file(std::env::temp_dir().join("day17-export-test.svg"), Format::Svg);
+        boot::<3>(&slice, &mut frames).unwrap();
+        assert_eq!(CYCLES + 1, frames.count());
+    }
+}
@@ -0,0 +1,72 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::generate::Generated;
+use rand::Rng;
+
+/// Width and height of the starting slice in a generated input.
+pub const DEFAULT_SIZE: usize = 8;
+
+/// A `size` by `size` starting slice with about half its cubes active.
+///
+/// The answers are worked out by running the six cycles over a set of active cubes, which
+/// grows with them rather than needing a grid.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+    let size = size.max(1);
+    let slice: Vec<Vec<bool>> = (0..size).map(|_| (0..size).map(|_| rng.gen_bool(0.5)).collect()).collect();
+    let active: HashSet<[i32; 4]> = slice.iter().enumerate()
+        .flat_map(|(y, row)| row.iter().enumerate().filter(|(_, &on)| on).map(move |(x, _)| [x as i32, y as i32, 0, 0]))
+        .collect();
+
+    let input = slice.iter()
+        .map(|row| row.iter().map(|&on| if on { '#' } else { '.' }).chain(Some('\n')).collect::<String>())
+        .collect();
+    let part1 = boot(active.clone(), 3);
+    let part2 = boot(active, 4);
+    Generated::new(input, Some(part1.to_string()), Some(part2.to_string()))
+}
+
+/// Runs six cycles in the first `dimensions` dimensions and counts the active cubes left.
+fn boot(mut active: HashSet<[i32; 4]>, dimensions: usize) -> usize {
+    let offsets: Vec<[i32; 4]> = (0..3i32.pow(dimensions as u32))
+        .map(|mut n| {
+            let mut offset = [0; 4];
+            for axis in offset.iter_mut().take(dimensions) {
+                *axis = n % 3 - 1;
+                n /= 3;
+            }
+            offset
+        })
+        .filter(|offset| offset != &[0; 4])
+        .collect();
+
+    for _ in 0..6 {
+        let mut neighbours: HashMap<[i32; 4], usize> = HashMap::new();
+        for cube in &active {
+            for offset in &offsets {
+                let mut neighbour = *cube;
+                neighbour.iter_mut().zip(offset).for_each(|(axis, offset)| *axis += offset);
+                *neighbours.entry(neighbour).or_default() += 1;
+            }
+        }
+        active = neighbours.into_iter()
+            .filter(|(cube, count)| *count == 3 || (*count == 2 && active.contains(cube)))
+            .map(|(cube, _)| cube)
+            .collect();
+    }
+    active.len()
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day17;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..2 {
+            generate(&mut StdRng::seed_from_u64(seed), 4).assert_solves::<Day17>();
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use std::fmt::Write;
+
+use aoc_automaton::{Automaton, Lattice, Rule};
+use aoc_core::{diag, ParseError, Solver};
+use aoc_grid::{Cell, Grid};
+
+#[cfg(feature = "export")]
+pub mod export;
+#[cfg(feature = "generate")]
+pub mod generate;
+#[cfg(feature = "repl")]
+pub mod repl;
+
+/// Day 17: Conway Cubes
+pub struct Day17;
+
+impl Solver for Day17 {
+    const DAY: u8 = 17;
+
+    type Input<'a> = Grid<bool>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        process_input(input)
+    }
+
+    fn part1(slice: &Self::Input<'_>) -> usize {
+        get_answer(slice)
+    }
+
+    fn part2(slice: &Self::Input<'_>) -> usize {
+        get_answer2(slice)
+    }
+}
+
+/// Parses the starting 2D slice of active (`#`) and inactive (`.`) cubes.
+fn process_input(input: &str) -> Result<Grid<bool>, ParseError> {
+    let slice = Grid::<bool>::parse(input)?;
+    if slice.width() == 0 {
+        return Err(ParseError::new(1, "a row of cubes", ""));
+    }
+    Ok(slice)
+}
+
+fn get_answer(slice: &Grid<bool>) -> usize {
+    let mut cubes = pocket_dimension::<3>(slice);
+    for cycle in 1..=CYCLES {
+        cubes.step();
+        diag!(2, "After {} cycles:\n{}", cycle, render(&cubes));
+    }
+    cubes.count()
+}
+
+fn get_answer2(slice: &Grid<bool>) -> usize {
+    let mut cubes = pocket_dimension::<4>(slice);
+    cubes.run(CYCLES);
+    cubes.count()
+}
+
+/// How many cycles the boot process runs for.
+pub const CYCLES: usize = 6;
+
+/// Active cubes stay active with 2 or 3 active neighbours, and inactive ones become active with
+/// exactly 3.
+pub const RULE: Rule = Rule::new(&[3], &[2, 3]);
+
+/// The pocket dimension in `N` dimensions, where the active cubes are those of `slice`, at 0 along
+/// every other axis.
+pub fn pocket_dimension<const N: usize>(slice: &Grid<bool>) -> Automaton<Lattice<N>> {
+    let active = slice.positions().filter(|&point| slice[point]).map(|(x, y)| {
+        let mut cube = [0; N];
+        cube[0] = x as i32;
+        cube[1] = y as i32;
+        cube
+    });
+    Automaton::new(Lattice, RULE, active)
+}
+
+/// Names of the axes, in order.
+const AXES: [&str; 4] = ["x", "y", "z", "w"];
+
+/// Draws each `x`-`y` layer from the lowest up, over the smallest box that holds every active
+/// cube, headed with where the layer is along the other axes.
+pub fn render<const N: usize>(cubes: &Automaton<Lattice<N>>) -> String {
+    let range = |axis: usize| {
+        let values = cubes.live().iter().map(|cube| cube[axis]);
+        values.clone().min().unwrap_or(0)..=values.max().unwrap_or(-1)
+    };
+    let (xs, ys) = (range(0), range(1));
+
+    // Every place along the axes past `y`, with `z` changing fastest.
+    let layers = (2..N).rev().fold(vec![vec![]], |layers, axis| {
+        layers.into_iter()
+            .flat_map(|layer: Vec<i32>| range(axis).map(move |value| [&[value][..], &layer].concat()))
+            .collect()
+    });
+
+    let mut rendered = String::new();
+    for layer in layers {
+        let heading: Vec<String> = layer.iter()
+            .enumerate()
+            .map(|(i, value)| format!("{}={}", AXES.get(i + 2).unwrap_or(&"?"), value))
+            .collect();
+        writeln!(rendered, "{}", heading.join(", ")).unwrap();
+        for y in ys.clone() {
+            rendered.extend(xs.clone().map(|x| {
+                let mut cube = [0; N];
+                cube[0] = x;
+                cube[1] = y;
+                cube[2..].copy_from_slice(&layer);
+                cubes.is_live(cube).to_char()
+            }));
+            rendered.push('\n');
+        }
+        rendered.push('\n');
+    }
+    rendered
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "\
+.#.
+..#
+###";
+
+    #[test]
+    fn test_answer() {
+        let slice = process_input(INPUT).unwrap();
+        assert_eq!(112, get_answer(&slice));
+    }
+
+    const INPUT2: &str = "\
+.#.
+..#
+###";
+
+    #[test]
+    fn test_answer2() {
+        let slice = process_input(INPUT2).unwrap();
+        assert_eq!(848, get_answer2(&slice));
+    }
+}
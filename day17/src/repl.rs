@@ -0,0 +1,108 @@
+use aoc_automaton::{Automaton, Lattice};
+use aoc_core::repl::{self, Command, Explore, Session};
+use aoc_grid::Grid;
+
+use crate::{pocket_dimension, render, Day17};
+
+const COMMANDS: &[Command] = &[
+    Command { name: "step", args: "[n]", help: "Run n cycles, or 1, and show the cubes" },
+    Command { name: "show", args: "", help: "Show the active cubes, layer by layer" },
+    Command { name: "count", args: "", help: "Count the active cubes" },
+    Command { name: "dimensions", args: "<3|4>", help: "Start again in 3 dimensions, as in part 1, or 4, as in part 2" },
+    Command { name: "reset", args: "", help: "Start again from the input" },
+];
+
+/// The pocket dimension in as many dimensions as it's being run in.
+enum Cubes {
+    Three(Automaton<Lattice<3>>),
+    Four(Automaton<Lattice<4>>),
+}
+
+/// The pocket dimension some cycles in.
+struct Pocket {
+    slice: Grid<bool>,
+    cubes: Cubes,
+    cycles: usize,
+}
+
+impl Pocket {
+    fn show(&self) -> String {
+        let layers = match &self.cubes {
+            Cubes::Three(cubes) => render(cubes),
+            Cubes::Four(cubes) => render(cubes),
+        };
+        format!("After {} cycles:\n\n{}", self.cycles, layers)
+    }
+
+    fn count(&self) -> usize {
+        match &self.cubes {
+            Cubes::Three(cubes) => cubes.count(),
+            Cubes::Four(cubes) => cubes.count(),
+        }
+    }
+}
+
+impl Explore for Day17 {
+    fn explore<'a>(slice: Self::Input<'a>) -> Box<dyn Session + 'a> {
+        let cubes = Cubes::Three(pocket_dimension(&slice));
+        Box::new(Pocket { slice, cubes, cycles: 0 })
+    }
+}
+
+impl Session for Pocket {
+    fn commands(&self) -> &'static [Command] {
+        COMMANDS
+    }
+
+    fn run(&mut self, command: &str, args: &str) -> Result<String, String> {
+        match command {
+            "step" => {
+                let steps = repl::count(args)?;
+                match &mut self.cubes {
+                    Cubes::Three(cubes) => cubes.run(steps),
+                    Cubes::Four(cubes) => cubes.run(steps),
+                }
+                self.cycles += steps;
+                Ok(self.show())
+            }
+            "show" => Ok(self.show()),
+            "count" => Ok(self.count().to_string()),
+            "dimensions" => {
+                self.cubes = match args {
+                    "3" => Cubes::Three(pocket_dimension(&self.slice)),
+                    "4" => Cubes::Four(pocket_dimension(&self.slice)),
+                    _ => return Err(format!("dimensions must be 3 or 4, not `{}`", args)),
+                };
+                self.cycles = 0;
+                Ok(String::new())
+            }
+            "reset" => {
+                self.cubes = match self.cubes {
+                    Cubes::Three(_) => Cubes::Three(pocket_dimension(&self.slice)),
+                    Cubes::Four(_) => Cubes::Four(pocket_dimension(&self.slice)),
+                };
+                self.cycles = 0;
+                Ok(String::new())
+            }
+            _ => unreachable!("not one of the commands"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_session() {
+        let mut pocket = Day17::explore(Grid::parse(".#.\n..#\n###").unwrap());
+        pocket.run("step", "6").unwrap();
+        assert_eq!(Ok("112".to_owned()), pocket.run("count", ""));
+
+        pocket.run("dimensions", "4").unwrap();
+        let shown = pocket.run("step", "").unwrap();
+        assert!(shown.starts_with("After 1 cycles:\n\nz=-1, w=-1\n#..\n..#\n.#.\n\nz=0, w=-1\n"), "{}", shown);
+        pocket.run("reset", "").unwrap();
+        assert_eq!(Ok("5".to_owned()), pocket.run("count", ""));
+    }
+}
@@ -0,0 +1,108 @@
+use aoc_core::generate::Generated;
+use rand::Rng;
+
+/// Number of lines of generated homework.
+pub const DEFAULT_SIZE: usize = 380;
+
+/// Lines whose value would pass this under either set of rules are thrown away, so the sums
+/// stay in range.
+const MAX_VALUE: i128 = 1_000_000_000_000;
+
+enum Term {
+    Number(i128),
+    Group(Box<Expr>),
+}
+
+/// Terms joined by operators: `true` for `+`, `false` for `*`.
+struct Expr {
+    first: Term,
+    rest: Vec<(bool, Term)>,
+}
+
+/// `size` lines of homework, each with two to six single-digit terms and parentheses up to two
+/// deep.
+///
+/// The answers come from building each line as a tree and evaluating it both ways.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+    let (mut sum, mut sum2) = (0, 0);
+    let mut input = String::new();
+    while input.lines().count() < size {
+        let expr = expr(rng, 0);
+        let (value, value2) = (evaluate(&expr), evaluate2(&expr));
+        if value <= MAX_VALUE && value2 <= MAX_VALUE {
+            sum += value;
+            sum2 += value2;
+            input += &format!("{}\n", render(&expr));
+        }
+    }
+    Generated::new(input, Some(sum.to_string()), Some(sum2.to_string()))
+}
+
+fn expr<R: Rng + ?Sized>(rng: &mut R, depth: usize) -> Expr {
+    let term = |rng: &mut R| {
+        if depth < 2 && rng.gen_bool(0.25) {
+            Term::Group(Box::new(expr(rng, depth + 1)))
+        } else {
+            Term::Number(rng.gen_range(1..=9))
+        }
+    };
+    let first = term(rng);
+    let rest = (1..rng.gen_range(2..=6)).map(|_| (rng.gen_bool(0.5), term(rng))).collect();
+    Expr { first, rest }
+}
+
+fn render(expr: &Expr) -> String {
+    let term = |term: &Term| match term {
+        Term::Number(n) => n.to_string(),
+        Term::Group(expr) => format!("({})", render(expr)),
+    };
+    let mut text = term(&expr.first);
+    for (add, next) in &expr.rest {
+        text += if *add { " + " } else { " * " };
+        text += &term(next);
+    }
+    text
+}
+
+fn value(term: &Term, evaluate: fn(&Expr) -> i128) -> i128 {
+    match term {
+        Term::Number(n) => *n,
+        Term::Group(expr) => evaluate(expr),
+    }
+}
+
+/// Left to right, `+` and `*` alike.
+fn evaluate(expr: &Expr) -> i128 {
+    expr.rest.iter().fold(value(&expr.first, evaluate), |total, (add, term)| {
+        if *add { total + value(term, evaluate) } else { total * value(term, evaluate) }
+    })
+}
+
+/// Sums first, then the product of the sums.
+fn evaluate2(expr: &Expr) -> i128 {
+    let mut sums = vec![value(&expr.first, evaluate2)];
+    for (add, term) in &expr.rest {
+        let term = value(term, evaluate2);
+        if *add {
+            *sums.last_mut().unwrap() += term;
+        } else {
+            sums.push(term);
+        }
+    }
+    sums.iter().product()
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day18;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            generate(&mut StdRng::seed_from_u64(seed), 50).assert_solves::<Day18>();
+        }
+    }
+}
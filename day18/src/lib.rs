@@ -0,0 +1,276 @@
+use std::str::FromStr;
+
+use aoc_core::{parse, ParseError, Solver};
+use aoc_nom::{context, tag, unsigned, IResult};
+use nom::{
+    branch::alt,
+    character::complete::{char, multispace0},
+    combinator::{cut, map, value},
+    multi::many1,
+    sequence::{preceded, terminated},
+};
+
+#[cfg(feature = "generate")]
+pub mod generate;
+#[cfg(feature = "repl")]
+pub mod repl;
+
+/// Day 18: Operation Order
+pub struct Day18;
+
+impl Solver for Day18 {
+    const DAY: u8 = 18;
+
+    type Input<'a> = Vec<Expression>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        process_input(input)
+    }
+
+    fn part1(expressions: &Self::Input<'_>) -> i64 {
+        get_answer(expressions)
+    }
+
+    fn part2(expressions: &Self::Input<'_>) -> i64 {
+        get_answer2(expressions)
+    }
+}
+
+fn process_input(input: &str) -> Result<Vec<Expression>, ParseError> {
+    parse::lines(input, Expression::from_str)
+}
+
+fn get_answer(expressions: &[Expression]) -> i64 {
+    expressions.iter().map(Expression::evaluate).sum()
+}
+
+fn get_answer2(expressions: &[Expression]) -> i64 {
+    expressions.iter().map(Expression::evaluate2).sum()
+}
+
+/// A line of homework, e.g. `2 * 3 + (4 * 5)`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Expression(Vec<Token>);
+
+/// Parses numbers, `+`, `*` and parentheses; spaces between them are optional.
+impl FromStr for Expression {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        aoc_nom::finish(s, parse_expression)
+    }
+}
+
+impl Expression {
+    /// Evaluates left to right, with `+` and `*` binding equally tightly.
+    pub fn evaluate(&self) -> i64 {
+        self.checked_evaluate().expect("the homework overflows an i64")
+    }
+
+    /// Evaluates with `+` binding more tightly than `*`.
+    pub fn evaluate2(&self) -> i64 {
+        self.checked_evaluate2().expect("the homework overflows an i64")
+    }
+
+    /// As [`Expression::evaluate`], or `None` if any step overflows an `i64`.
+    pub fn checked_evaluate(&self) -> Option<i64> {
+        evaluate_in_turn(&self.0, Expression::checked_evaluate)
+    }
+
+    /// As [`Expression::evaluate2`], or `None` if any step overflows an `i64`.
+    pub fn checked_evaluate2(&self) -> Option<i64> {
+        self.0.split(|o| *o == Token::Operator(Operator::Multiply))
+            .try_fold(1i64, |product, ops| product.checked_mul(evaluate_in_turn(ops, Expression::checked_evaluate2)?))
+    }
+}
+
+/// Evaluates `tokens` left to right, evaluating parentheticals with `evaluator`.
+fn evaluate_in_turn(tokens: &[Token], evaluator: fn(&Expression) -> Option<i64>) -> Option<i64> {
+    let mut total = 0i64;
+    let mut operator = Operator::Add;
+    for token in tokens.iter() {
+        if let Token::Operator(op) = token {
+            operator = op.clone();
+        } else {
+            let num = token.evaluate(evaluator)?;
+            total = match operator {
+                Operator::Add => total.checked_add(num)?,
+                Operator::Multiply => total.checked_mul(num)?,
+            };
+        }
+    }
+    Some(total)
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Token {
+    Number(i64),
+    Operator(Operator),
+    Parenthetical(Expression)
+}
+
+impl Token {
+    /// The token's value, or `None` if it's an operator or `evaluator` overflows.
+    fn evaluate<F: FnOnce(&Expression) -> Option<i64>>(&self, evaluator: F) -> Option<i64> {
+        match self {
+            Token::Number(num) => Some(*num),
+            Token::Parenthetical(exp) => evaluator(exp),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Operator {
+    Add,
+    Multiply,
+}
+
+fn parse_number(i: &str) -> IResult<'_, Token> {
+    map(
+        unsigned,
+        Token::Number,
+    )(i)
+}
+
+fn parse_operator(i: &str) -> IResult<'_, Token> {
+    let add = value(Operator::Add, tag("+"));
+    let multiply = value(Operator::Multiply, tag("*"));
+
+    map(
+        alt((add, multiply)),
+        Token::Operator,
+    )(i)
+}
+
+fn parse_parenthetical(i: &str) -> IResult<'_, Token> {
+    map(
+        preceded(
+            char('('),
+            cut(
+                terminated(
+                    parse_expression,
+                    preceded(multispace0, char(')'))
+                )
+            )
+        ),
+        Token::Parenthetical
+    )(i)
+}
+
+fn parse_operation(i: &str) -> IResult<'_, Token> {
+    preceded(
+        multispace0,
+        context("a number, `+`, `*` or `(`", alt((
+            parse_number,
+            parse_operator,
+            parse_parenthetical,
+        )))
+    )(i)
+}
+
+fn parse_expression(i: &str) -> IResult<'_, Expression> {
+    map(
+        many1(parse_operation),
+        Expression,
+    )(i)
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// An expression tree, which is what the homework would mean with every parenthesis written.
+    #[derive(Clone, Debug)]
+    enum Tree {
+        Number(i64),
+        Add(Box<Tree>, Box<Tree>),
+        Multiply(Box<Tree>, Box<Tree>),
+    }
+
+    impl Tree {
+        /// The reference evaluator.
+        fn value(&self) -> i64 {
+            match self {
+                Tree::Number(num) => *num,
+                Tree::Add(left, right) => left.value() + right.value(),
+                Tree::Multiply(left, right) => left.value() * right.value(),
+            }
+        }
+
+        /// Writes the tree with no more parentheses than it needs when the operators bind equally
+        /// tightly, or with `+` binding more tightly if `add_first`.
+        fn render(&self, add_first: bool) -> String {
+            let (left, op, right) = match self {
+                Tree::Number(num) => return num.to_string(),
+                Tree::Add(left, right) => (left, '+', right),
+                Tree::Multiply(left, right) => (left, '*', right),
+            };
+            let left_needs_group = add_first && op == '+' && matches!(**left, Tree::Multiply(..));
+            let group = |tree: &Tree, needed: bool| {
+                if needed { format!("({})", tree.render(add_first)) } else { tree.render(add_first) }
+            };
+            let right_needs_group = !matches!(**right, Tree::Number(_));
+            format!("{} {} {}", group(left, left_needs_group), op, group(right, right_needs_group))
+        }
+    }
+
+    /// Trees of at most 16 single-digit numbers, which keeps every value well inside an `i64`.
+    fn tree() -> impl Strategy<Value=Tree> {
+        (0..10i64).prop_map(Tree::Number).prop_recursive(4, 16, 2, |inner| {
+            prop_oneof![
+                (inner.clone(), inner.clone()).prop_map(|(l, r)| Tree::Add(Box::new(l), Box::new(r))),
+                (inner.clone(), inner).prop_map(|(l, r)| Tree::Multiply(Box::new(l), Box::new(r))),
+            ]
+        })
+    }
+
+    const INPUT: &str = "2 * 3 + (4 * 5)";
+    const INPUT2: &str = "5 + (8 * 3 + 9 + 3 * 4 * 3)";
+    const INPUT3: &str = "5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))";
+    const INPUT4: &str = "((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2";
+
+    #[test]
+    fn test_answer() {
+        let expressions = process_input(INPUT).unwrap();
+        assert_eq!(26, get_answer(&expressions));
+    }
+
+    #[test]
+    fn test_answer2() {
+        assert_eq!(46, get_answer2(&process_input(INPUT).unwrap()));
+        assert_eq!(1445, get_answer2(&process_input(INPUT2).unwrap()));
+        assert_eq!(669060, get_answer2(&process_input(INPUT3).unwrap()));
+        assert_eq!(23340, get_answer2(&process_input(INPUT4).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = process_input("1 + 2\n2 * (3 + 4").err();
+        assert_eq!(Some(ParseError::new(11, "`)`", "").on_line(2)), error);
+
+        let error = process_input("2 * (3 / 4)").err();
+        assert_eq!(Some(ParseError::new(8, "`)`", "/")), error);
+
+        let error = process_input("2 * 3 / 4").err();
+        assert_eq!(Some(ParseError::new(7, "end of input", "/")), error);
+    }
+
+    proptest! {
+        #[test]
+        fn evaluate_agrees_with_reference(tree in tree()) {
+            let expression = Expression::from_str(&tree.render(false)).unwrap();
+            prop_assert_eq!(tree.value(), expression.evaluate());
+        }
+
+        #[test]
+        fn evaluate2_agrees_with_reference(tree in tree()) {
+            let expression = Expression::from_str(&tree.render(true)).unwrap();
+            prop_assert_eq!(tree.value(), expression.evaluate2());
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use aoc_core::repl::{Command, Explore, Session};
+use aoc_core::ParseError;
+
+use crate::{Day18, Expression};
+
+const COMMANDS: &[Command] = &[
+    Command { name: "eval", args: "<expression>", help: "Evaluate an expression by both parts' rules" },
+    Command { name: "line", args: "<n>", help: "Evaluate the nth line of the homework by both parts' rules" },
+];
+
+/// The homework, one expression per line.
+struct Homework(Vec<Expression>);
+
+fn evaluate(expression: &Expression) -> Result<String, String> {
+    match (expression.checked_evaluate(), expression.checked_evaluate2()) {
+        (Some(value), Some(value2)) => Ok(format!("part 1: {}, part 2: {}", value, value2)),
+        _ => Err("overflows an i64".to_owned()),
+    }
+}
+
+impl Explore for Day18 {
+    fn explore<'a>(homework: Self::Input<'a>) -> Box<dyn Session + 'a> {
+        Box::new(Homework(homework))
+    }
+}
+
+impl Session for Homework {
+    fn commands(&self) -> &'static [Command] {
+        COMMANDS
+    }
+
+    fn run(&mut self, command: &str, args: &str) -> Result<String, String> {
+        match command {
+            "eval" => {
+                let expression = args.parse().map_err(|e: ParseError| e.render("<repl>", args))?;
+                evaluate(&expression)
+            }
+            "line" => {
+                let line: usize = args.parse().map_err(|_| format!("expected a line number, not `{}`", args))?;
+                let expression = line.checked_sub(1)
+                    .and_then(|index| self.0.get(index))
+                    .ok_or_else(|| format!("the homework has lines 1 to {}", self.0.len()))?;
+                evaluate(expression)
+            }
+            _ => unreachable!("not one of the commands"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::process_input;
+
+    #[test]
+    fn test_session() {
+        let mut homework = Day18::explore(process_input("1 + 2 * 3\n2 * 3 + (4 * 5)").unwrap());
+        assert_eq!(Ok("part 1: 26, part 2: 46".to_owned()), homework.run("eval", "2 * 3 + (4 * 5)"));
+        assert_eq!(Ok("part 1: 9, part 2: 9".to_owned()), homework.run("line", "1"));
+        assert!(homework.run("line", "0").is_err());
+        assert!(homework.run("eval", "2 * (3").unwrap_err().contains("<repl>"));
+        assert_eq!(Err("overflows an i64".to_owned()), homework.run("eval", "99999999999 * 99999999999"));
+        assert_eq!(Err("overflows an i64".to_owned()), homework.run("eval", "1 + (99999999999 * (99999999999))"));
+    }
+}
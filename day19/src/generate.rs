@@ -0,0 +1,111 @@
+use aoc_core::generate::Generated;
+use rand::{seq::SliceRandom, Rng};
+
+/// Number of messages in a generated input.
+pub const DEFAULT_SIZE: usize = 400;
+
+/// Rules 42 and 31 match strings of `2^LEVELS` letters.
+const LEVELS: usize = 3;
+
+/// How a pair of rules at one level is built from the pair below: the combinations of the lower
+/// pair, as `(first, second)` with `true` for the lower "P" rule, that make up the upper "P" rule.
+/// The upper "Q" rule is every other combination.
+type Level = Vec<(bool, bool)>;
+
+/// Rules in the shape of the real puzzle's, `0: 8 11`, `8: 42` and `11: 42 31`, and `size`
+/// messages.
+///
+/// Rules 42 and 31 are built up level by level from a pair of rules that split the strings of
+/// each length between them, starting with `"a"` and `"b"`. So every message whose length is a
+/// multiple of theirs reads as one sequence of 42s and 31s, and the answers follow from the
+/// sequences the messages were built from.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+    let combinations = [(true, true), (true, false), (false, true), (false, false)];
+    let levels: Vec<Level> = (0..LEVELS).map(|_| {
+        let count = rng.gen_range(1..=3);
+        combinations.choose_multiple(rng, count).copied().collect()
+    }).collect();
+
+    // The rule numbers of each level's P and Q rules, the top level being 42 and 31.
+    let mut free: Vec<usize> = (1..2 * LEVELS + 20).filter(|n| ![8, 11, 31, 42].contains(n)).collect();
+    free.shuffle(rng);
+    let mut numbers: Vec<(usize, usize)> = (0..LEVELS).map(|level| (free[2 * level], free[2 * level + 1])).collect();
+    numbers.push((42, 31));
+
+    let mut rules = vec![
+        "0: 8 11".to_owned(),
+        "8: 42".to_owned(),
+        "11: 42 31".to_owned(),
+        format!("{}: \"a\"", numbers[0].0),
+        format!("{}: \"b\"", numbers[0].1),
+    ];
+    for (level, combos) in levels.iter().enumerate() {
+        let (p, q) = numbers[level];
+        let rule = |combo: &(bool, bool)| {
+            let pick = |is_p: bool| if is_p { p } else { q };
+            format!("{} {}", pick(combo.0), pick(combo.1))
+        };
+        let others: Vec<_> = combinations.iter().filter(|combo| !combos.contains(combo)).collect();
+        let (upper_p, upper_q) = numbers[level + 1];
+        rules.push(format!("{}: {}", upper_p, combos.iter().map(rule).collect::<Vec<_>>().join(" | ")));
+        rules.push(format!("{}: {}", upper_q, others.into_iter().map(rule).collect::<Vec<_>>().join(" | ")));
+    }
+    rules.shuffle(rng);
+
+    let chunk = 1 << LEVELS;
+    let (mut valid, mut valid2) = (0, 0);
+    let messages: Vec<String> = (0..size).map(|_| {
+        if rng.gen_bool(0.15) {
+            let len = chunk * rng.gen_range(0..4) + rng.gen_range(1..chunk);
+            return (0..len).map(|_| if rng.gen_bool(0.5) { 'a' } else { 'b' }).collect();
+        }
+
+        // `true` for a 42, `false` for a 31.
+        let mut labels = if rng.gen_bool(0.2) {
+            vec![true, true, false]
+        } else {
+            let (fours, threes) = (rng.gen_range(1..=5), rng.gen_range(1..=4));
+            [vec![true; fours], vec![false; threes]].concat()
+        };
+        if rng.gen_bool(0.3) {
+            labels.shuffle(rng);
+        }
+
+        let fours = labels.iter().take_while(|&&label| label).count();
+        let threes = labels.len() - fours;
+        valid += (labels == [true, true, false]) as usize;
+        valid2 += (labels[fours..].iter().all(|&label| !label) && fours > threes && threes >= 1) as usize;
+        labels.iter().map(|&label| sample(rng, &levels, LEVELS, label)).collect()
+    }).collect();
+
+    let input = format!("{}\n\n{}\n", rules.join("\n"), messages.join("\n"));
+    Generated::new(input, Some(valid.to_string()), Some(valid2.to_string()))
+}
+
+/// A random string matched by the P rule (`is_p`) or the Q rule at `level`.
+fn sample<R: Rng + ?Sized>(rng: &mut R, levels: &[Level], level: usize, is_p: bool) -> String {
+    if level == 0 {
+        return if is_p { "a" } else { "b" }.to_owned();
+    }
+    let combos: Vec<(bool, bool)> = [(true, true), (true, false), (false, true), (false, false)].iter()
+        .copied()
+        .filter(|combo| levels[level - 1].contains(combo) == is_p)
+        .collect();
+    let (first, second) = *combos.choose(rng).unwrap();
+    sample(rng, levels, level - 1, first) + &sample(rng, levels, level - 1, second)
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day19;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            generate(&mut StdRng::seed_from_u64(seed), 50).assert_solves::<Day19>();
+        }
+    }
+}
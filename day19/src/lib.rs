@@ -0,0 +1,259 @@
+use std::collections::{HashMap, HashSet};
+use std::iter::FromIterator;
+
+use aoc_core::{parse, ParseError, Solver};
+
+#[cfg(feature = "generate")]
+pub mod generate;
+#[cfg(feature = "repl")]
+pub mod repl;
+
+/// Day 19: Monster Messages
+pub struct Day19;
+
+impl Solver for Day19 {
+    const DAY: u8 = 19;
+
+    type Input<'a> = (RuleSet<'a>, Vec<&'a str>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        process_input(input)
+    }
+
+    fn part1((rules, messages): &Self::Input<'_>) -> usize {
+        get_answer(rules, messages)
+    }
+
+    fn part2((rules, messages): &Self::Input<'_>) -> usize {
+        get_answer2(rules, messages)
+    }
+}
+
+fn process_input(input: &str) -> Result<(RuleSet<'_>, Vec<&str>), ParseError> {
+    let mut iter = input.split("\n\n");
+    let rules = parse::lines(iter.next().unwrap_or(""), Rule::parse)?;
+    let messages = iter.next().unwrap_or("").lines().collect();
+    Ok((rules.into_iter().collect(), messages))
+}
+
+fn get_answer(rules: &RuleSet, messages: &[&str]) -> usize {
+    messages.iter()
+        .filter(|message| {
+            rules.validate(0, message)
+        })
+        .count()
+}
+
+fn get_answer2(rules: &RuleSet, messages: &[&str]) -> usize {
+    let rules = looping(rules);
+    messages.iter()
+    .filter(|message| {
+        rules.validate(0, message)
+    })
+    .count()
+}
+
+/// Part 2's rules, where rules 8 and 11 refer to themselves.
+pub fn looping<'a>(rules: &RuleSet<'a>) -> RuleSet<'a> {
+    let mut rules = rules.clone();
+    let (_, rule8) = Rule::parse("8: 42 | 42 8").unwrap();
+    let (_, rule11) = Rule::parse("11: 42 31 | 42 11 31").unwrap();
+
+    rules.insert(8, rule8);
+    rules.insert(11, rule11);
+    rules
+}
+
+/// A grammar of numbered rules that messages are matched against.
+#[derive(Clone, Default)]
+pub struct RuleSet<'a>(HashMap<usize, Rule<'a>>);
+
+impl<'a> RuleSet<'a> {
+    /// Adds a rule, replacing any rule already at `index`.
+    pub fn insert(&mut self, index: usize, rule: Rule<'a>) {
+        self.0.insert(index, rule);
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        self.0.contains_key(&index)
+    }
+
+    /// Whether rule `rule_index` matches the whole of `message`.
+    ///
+    /// Rules may refer to themselves, as long as they consume some of the message first. Panics
+    /// if a rule that's needed is missing.
+    pub fn validate(&self, rule_index: usize, message: &str) -> bool {
+        let rule_to_validate = self.0.get(&rule_index).unwrap();
+        let remainders = rule_to_validate.validate(message, self);
+        remainders.into_iter().any(str::is_empty)
+    }
+}
+
+impl<'a> FromIterator<(usize, Rule<'a>)> for RuleSet<'a> {
+    fn from_iter<I: IntoIterator<Item = (usize, Rule<'a>)>>(rules: I) -> Self {
+        RuleSet(rules.into_iter().collect())
+    }
+}
+
+/// One rule of the grammar.
+#[derive(Clone, Debug)]
+pub enum Rule<'a> {
+    /// Matches this text exactly, e.g. `"a"`.
+    Terminator(&'a str),
+    /// Matches any one of these sequences of other rules, e.g. `1 2 | 2 1`.
+    Reference(HashSet<Vec<usize>>),
+}
+
+impl<'a> Rule<'a> {
+    /// Parses a line such as `0: 4 1 5`, returning the rule's index with it.
+    pub fn parse(input: &'a str) -> Result<(usize, Self), ParseError> {
+        let (index, text) = input.split_once(": ")
+            .ok_or_else(|| ParseError::at(input, input, "`<index>: <rule>`"))?;
+        let index = parse::number(input, index)?;
+
+        if let Some(tail) = text.strip_prefix('"') {
+            let body = tail.strip_suffix('"')
+                .ok_or_else(|| ParseError::at(input, &tail[tail.len()..], "a closing `\"`"))?;
+            Ok((index, Rule::Terminator(body)))
+        } else {
+            let h = text.split(" | ").map(|s| {
+                s.split(' ').map(|s| parse::number(input, s)).collect()
+            }).collect::<Result<_, _>>()?;
+            Ok((index, Rule::Reference(h)))
+        }
+    }
+
+    /// What is left of `message` after each of the ways this rule can match its start.
+    pub fn validate<'s, 'r: 's>(&'r self, message: &'s str, rules: &'r RuleSet) -> Box<dyn Iterator<Item=&'s str> + 's> {
+        if message.is_empty() { return Box::new(None.into_iter()) }
+
+        match self {
+            Rule::Terminator(s) => {
+                if message.starts_with(s) {
+                    let (_matched, unmatched) = message.split_at(s.len());
+                    Box::new(Some(unmatched).into_iter())
+                } else {
+                    Box::new(None.into_iter())
+                }
+            }
+            Rule::Reference(branches) => {
+                Box::new(branches.iter().flat_map(move |sequence| {
+                    let mut remaining_message = vec![message];
+                    for rule in sequence.iter().map(|index| rules.0.get(index).unwrap()) {
+                        remaining_message = remaining_message.iter().flat_map(|mess|
+                            rule.validate(mess, rules)
+                        ).collect()
+                    }
+                    remaining_message.into_iter()
+                }))
+            }
+        }
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "\
+0: 4 1 5
+1: 2 3 | 3 2
+2: 4 4 | 5 5
+3: 4 5 | 5 4
+4: \"a\"
+5: \"b\"
+
+ababbb
+bababa
+abbbab
+aaabbb
+aaaabbb";
+
+    #[test]
+    fn test_answer() {
+        let (rules, messages) = process_input(INPUT).unwrap();
+        assert_eq!(2, get_answer(&rules, &messages));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = process_input(&INPUT.replace("3 | 3", "3 | x")).err();
+        assert_eq!(Some(ParseError::new(10, "a number", "x").on_line(2)), error);
+
+        let error = process_input(&INPUT.replace("\"b\"", "\"b")).err();
+        assert_eq!(Some(ParseError::new(6, "a closing `\"`", "").on_line(6)), error);
+
+        let error = process_input("0 4 1 5").err();
+        assert_eq!(Some(ParseError::new(1, "`<index>: <rule>`", "0 4 1 5")), error);
+    }
+
+    #[test]
+    fn test_terminator() {
+        let (_, rule) = Rule::parse("0: \"a\"").unwrap();
+        let rules = RuleSet(HashMap::new());
+        let mut matches = rule.validate("a", &rules);
+        assert_eq!("", matches.next().unwrap());
+    }
+
+    #[test]
+    fn test_answer2() {
+        let (rules, messages) = process_input(INPUT2).unwrap();
+        assert_eq!(3, get_answer(&rules, &messages));
+        assert_eq!(12, get_answer2(&rules, &messages));
+    }
+
+    const INPUT2: &str = "\
+42: 9 14 | 10 1
+9: 14 27 | 1 26
+10: 23 14 | 28 1
+1: \"a\"
+11: 42 31
+5: 1 14 | 15 1
+19: 14 1 | 14 14
+12: 24 14 | 19 1
+16: 15 1 | 14 14
+31: 14 17 | 1 13
+6: 14 14 | 1 14
+2: 1 24 | 14 4
+0: 8 11
+13: 14 3 | 1 12
+15: 1 | 14
+17: 14 2 | 1 7
+23: 25 1 | 22 14
+28: 16 1
+4: 1 1
+20: 14 14 | 1 15
+3: 5 14 | 16 1
+27: 1 6 | 14 18
+14: \"b\"
+21: 14 1 | 1 14
+25: 1 1 | 1 14
+22: 14 14
+8: 42
+26: 14 22 | 1 20
+18: 15 15
+7: 14 5 | 1 21
+24: 14 1
+
+abbbbbabbbaaaababbaabbbbabababbbabbbbbbabaaaa
+bbabbbbaabaabba
+babbbbaabbbbbabbbbbbaabaaabaaa
+aaabbbbbbaaaabaababaabababbabaaabbababababaaa
+bbbbbbbaaaabbbbaaabbabaaa
+bbbababbbbaaaaaaaabbababaaababaabab
+ababaaaaaabaaab
+ababaaaaabbbaba
+baabbaaaabbaaaababbaababb
+abbbbabbbbaaaababbbbbbaaaababb
+aaaaabbaabaaaaababaa
+aaaabbaaaabbaaa
+aaaabbaabbaaaaaaabbbabbbaaabbaabaaa
+babaaabbbaaabaababbaabababaaab
+aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba";
+
+
+}
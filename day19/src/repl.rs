@@ -0,0 +1,71 @@
+use aoc_core::repl::{Command, Explore, Session};
+
+use crate::{looping, Day19, RuleSet};
+
+const COMMANDS: &[Command] = &[
+    Command { name: "check", args: "<message>", help: "Check a message against rule 0, by both parts' rules" },
+    Command { name: "message", args: "<n>", help: "Check the nth received message against rule 0" },
+];
+
+/// The rules, both as given and with part 2's loops, and the messages received.
+struct Messages<'a> {
+    rules: RuleSet<'a>,
+    looping: Option<RuleSet<'a>>,
+    messages: Vec<&'a str>,
+}
+
+impl Messages<'_> {
+    fn check(&self, message: &str) -> String {
+        let verdict = |rules: &RuleSet| if rules.validate(0, message) { "matches" } else { "doesn't match" };
+        match &self.looping {
+            Some(looping) => format!("part 1: {}, part 2: {}", verdict(&self.rules), verdict(looping)),
+            None => format!("part 1: {}", verdict(&self.rules)),
+        }
+    }
+}
+
+impl Explore for Day19 {
+    fn explore<'a>((rules, messages): Self::Input<'a>) -> Box<dyn Session + 'a> {
+        // Part 2's loops are made of rules 42 and 31, which small examples can do without.
+        let looping = (rules.contains(42) && rules.contains(31)).then(|| looping(&rules));
+        Box::new(Messages { rules, looping, messages })
+    }
+}
+
+impl Session for Messages<'_> {
+    fn commands(&self) -> &'static [Command] {
+        COMMANDS
+    }
+
+    fn run(&mut self, command: &str, args: &str) -> Result<String, String> {
+        if !self.rules.contains(0) {
+            return Err("there is no rule 0 to check against".to_owned());
+        }
+        match command {
+            "check" => Ok(self.check(args)),
+            "message" => {
+                let n: usize = args.parse().map_err(|_| format!("expected a message number, not `{}`", args))?;
+                let message = n.checked_sub(1)
+                    .and_then(|index| self.messages.get(index))
+                    .ok_or_else(|| format!("there are messages 1 to {}", self.messages.len()))?;
+                Ok(format!("{}: {}", message, self.check(message)))
+            }
+            _ => unreachable!("not one of the commands"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::process_input;
+
+    #[test]
+    fn test_session() {
+        let input = "0: 1 2\n1: \"a\"\n2: 1 3 | 3 1\n3: \"b\"\n\naab\nabb";
+        let mut messages = Day19::explore(process_input(input).unwrap());
+        assert_eq!(Ok("part 1: matches".to_owned()), messages.run("check", "aba"));
+        assert_eq!(Ok("abb: part 1: doesn't match".to_owned()), messages.run("message", "2"));
+        assert!(messages.run("message", "3").is_err());
+    }
+}
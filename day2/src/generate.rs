@@ -0,0 +1,46 @@
+use aoc_core::generate::Generated;
+use rand::Rng;
+
+/// Number of lines in a generated password database.
+pub const DEFAULT_SIZE: usize = 1000;
+
+/// A password database of `size` lines, with passwords drawn mostly from a handful of letters so
+/// that both policies pass and fail often.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+    let letters = b"abcdefghijklmnopqrstuvwxyz";
+    let (mut valid, mut valid2) = (0, 0);
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let letter = letters[rng.gen_range(0..letters.len())] as char;
+        let len = rng.gen_range(3..=20);
+        let password: String = (0..len).map(|_| {
+            if rng.gen_bool(0.3) { letter } else { letters[rng.gen_range(0..6)] as char }
+        }).collect();
+        let min = rng.gen_range(1..len);
+        let max = rng.gen_range(min + 1..=len);
+
+        let count = password.chars().filter(|&c| c == letter).count();
+        valid += (min..=max).contains(&count) as usize;
+        let at = |position: usize| password.as_bytes()[position - 1] as char == letter;
+        valid2 += (at(min) != at(max)) as usize;
+
+        input += &format!("{}-{} {}: {}\n", min, max, letter, password);
+    }
+    Generated::new(input, Some(valid.to_string()), Some(valid2.to_string()))
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day2;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            generate(&mut StdRng::seed_from_u64(seed), 100).assert_solves::<Day2>();
+        }
+    }
+}
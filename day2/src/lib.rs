@@ -0,0 +1,102 @@
+use aoc_core::{parse, ParseError, Solver};
+use aoc_nom::{context, unsigned, IResult};
+use nom::{
+    bytes::complete::take_while1,
+    character::complete::{char, satisfy, space0},
+    sequence::delimited,
+};
+use std::fmt;
+use std::str::FromStr;
+
+use policy::{Count, Policy, Positions};
+
+#[cfg(feature = "generate")]
+pub mod generate;
+pub mod policy;
+
+/// Day 2: Password Philosophy
+pub struct Day2;
+
+impl Solver for Day2 {
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Password>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::lines(input, str::parse)
+    }
+
+    fn part1(entries: &Self::Input<'_>) -> usize {
+        get_answer(entries)
+    }
+
+    fn part2(entries: &Self::Input<'_>) -> usize {
+        get_answer2(entries)
+    }
+}
+
+fn get_answer(entries: &[Password]) -> usize {
+    entries.iter().filter(|p| p.validate()).count()
+}
+
+fn get_answer2(entries: &[Password]) -> usize {
+    entries.iter().filter(|p| p.validate2()).count()
+}
+
+/// A password alongside the policy it was set under, e.g. `1-3 a: abcde`.
+///
+/// The letter and password can be any characters other than whitespace, and positions count
+/// characters rather than bytes.
+#[derive(Debug)]
+pub struct Password {
+    pub letter: char,
+    pub min: usize,
+    pub max: usize,
+    pub password: String,
+}
+
+impl Password {
+    /// The old policy: `letter` appears between `min` and `max` times.
+    pub fn validate(&self) -> bool {
+        Count.validate(self)
+    }
+
+    /// The Toboggan policy: `letter` is at exactly one of the 1-based positions `min` and `max`.
+    pub fn validate2(&self) -> bool {
+        Positions.validate(self)
+    }
+}
+
+/// Writes the line as it appears in the database, e.g. `1-3 a: abcde`.
+impl fmt::Display for Password {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{} {}: {}", self.min, self.max, self.letter, self.password)
+    }
+}
+
+fn parse_password(s: &str) -> IResult<'_, Password> {
+    let (s, min) = unsigned(s)?;
+    let (s, _) = delimited(space0, char('-'), space0)(s)?;
+    let (s, max) = unsigned(s)?;
+    let (s, _) = space0(s)?;
+    let (s, letter) = context("a letter", satisfy(|c| !c.is_whitespace()))(s)?;
+    let (s, _) = delimited(space0, char(':'), space0)(s)?;
+    let (s, password) = context("a password", take_while1(|c: char| !c.is_whitespace()))(s)?;
+
+    Ok((s, Password{
+        min,
+        max,
+        letter,
+        password: password.to_string(),
+    }))
+}
+
+impl FromStr for Password {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        aoc_nom::finish(s, parse_password)
+    }
+}
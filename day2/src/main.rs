@@ -1,76 +1,64 @@
-use nom::{
-    IResult,
-    bytes::complete::{tag, take_while},
-    character::complete::{alpha1, anychar, digit1},
-    combinator::map_res,
-    error::{ParseError, FromExternalError},
-};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::num::ParseIntError;
-use std::str::FromStr;
+use std::process;
 
-fn main() {
-    let file = File::open("input").unwrap();
-    let reader = BufReader::new(file);
-    let entries: Vec<Password> = reader.lines().filter_map(|s| s.unwrap().parse().ok()).collect();
-    let answer = entries.into_iter().filter(Password::validate2).count();
-    println!("{}", answer);
-}
-
-#[derive(Debug)]
-struct Password {
-    pub letter: char,
-    pub min: usize,
-    pub max: usize,
-    pub password: String,
-}
-
-impl Password {
-    pub fn validate(&self) -> bool{
-        let count = self.password.chars().filter(|c| c == &self.letter).count();
-        (self.min <= count) && (count <= self.max)
-    }
+use aoc_core::output::Format;
+use aoc_core::DayArgs;
+use clap::Parser;
+use day2::policy::{self, Outcome};
+use day2::Day2;
+use serde_json::json;
 
-    pub fn validate2(&self) -> bool {
-        let chars: Vec<char> = self.password.chars().collect();
-        (self.letter == chars[self.min - 1]) ^ (self.letter == chars[self.max - 1])
-    }
-}
-
-fn space<'a, E: ParseError<&'a str>>(i: &'a str) -> IResult<&'a str, &'a str, E> {
-    let chars = " \t\r\n";
-    take_while(move |c| chars.contains(c))(i)
+/// Solve day 2 of Advent of Code 2020, or check the passwords against some other policy
+#[derive(Parser)]
+struct Args {
+    #[command(flatten)]
+    day: DayArgs,
+    /// Report how each password breaks this policy, and how many lines were valid, invalid or
+    /// couldn't be read, rather than solving the puzzle: `count`,
+    /// `positions`, `min-length=N`, `forbid=C`, `regex=PATTERN`, or `all(...)` or `any(...)`
+    /// around a comma-separated list of those
+    #[arg(long, value_name = "POLICY")]
+    policy: Option<String>,
 }
 
-fn parse_number<'a, E: ParseError<&'a str> + FromExternalError<&'a str, ParseIntError>>(i: &'a str) -> IResult<&'a str, usize, E> {
-    map_res(
-        digit1,
-        |number: &str| number.parse::<usize>()
-    )(i)
-}
+fn main() {
+    let args = Args::parse();
+    args.day.output.apply();
 
-impl FromStr for Password {
-    type Err = nom::Err<()>;
+    let policy = match &args.policy {
+        Some(policy) => policy::parse(policy).unwrap_or_else(|e| {
+            eprintln!("--policy: {}", e);
+            process::exit(2);
+        }),
+        None => return args.day.solve::<Day2>(),
+    };
+    let (source, buf) = args.day.read::<Day2>();
+    let (outcomes, summary) = policy::check(&buf, policy.as_ref());
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (s, min) = parse_number(s)?;
-        let (s, _) = space(s)?;
-        let (s, _) = tag("-")(s)?;
-        let (s, _) = space(s)?;
-        let (s, max) = parse_number(s)?;
-        let (s, _) = space(s)?;
-        let (s, letter) = anychar(s)?;
-        let (s, _) = space(s)?;
-        let (s, _) = tag(":")(s)?;
-        let (s, _) = space(s)?;
-        let (_, password) = alpha1(s)?;
+    let format = args.day.output.format;
+    for (index, outcome) in outcomes.iter().enumerate() {
+        let line = index + 1;
+        match (outcome, format) {
+            (Outcome::Valid(password), Format::Text) => println!("line {}: {}: valid", line, password),
+            (Outcome::Invalid(password, violations), Format::Text) => {
+                println!("line {}: {}: {}", line, password, policy::join(violations))
+            }
+            (Outcome::Malformed(e), Format::Text) => eprint!("{}", e.render(&source.to_string(), &buf)),
+            (Outcome::Valid(password), Format::Json) => {
+                println!("{}", json!({ "line": line, "password": password.to_string(), "violations": [] }))
+            }
+            (Outcome::Invalid(password, violations), Format::Json) => {
+                let violations: Vec<_> = violations.iter().map(ToString::to_string).collect();
+                println!("{}", json!({ "line": line, "password": password.to_string(), "violations": violations }));
+            }
+            (Outcome::Malformed(e), Format::Json) => println!("{}", json!({ "line": line, "malformed": e.to_string() })),
+        }
+    }
 
-        Ok(Password{
-            min,
-            max,
-            letter,
-            password: password.to_string(),
-        })
+    match format {
+        Format::Text => println!("{}", summary),
+        Format::Json => println!(
+            "{}",
+            json!({ "valid": summary.valid, "invalid": summary.invalid, "malformed": summary.malformed }),
+        ),
     }
 }
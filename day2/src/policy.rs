@@ -0,0 +1,402 @@
+//! Rules that passwords can be checked against, which can be combined and picked by name.
+//!
+//! [`Count`] and [`Positions`] are the two policies from the puzzle, and read their letter and
+//! numbers from each line of the database. The rest take theirs from the policy itself, and
+//! [`AllOf`] and [`AnyOf`] build bigger policies out of smaller ones. [`parse`] reads a policy
+//! from a description such as `all(positions, min-length=8, forbid=x)`.
+
+use std::fmt;
+
+use aoc_core::ParseError;
+use regex::Regex;
+
+use crate::Password;
+
+/// A rule a password can be checked against.
+pub trait Policy: fmt::Debug {
+    /// Every way `password` breaks this policy, which is none if it's valid.
+    fn violations(&self, password: &Password) -> Vec<PolicyViolation>;
+
+    fn validate(&self, password: &Password) -> bool {
+        self.violations(password).is_empty()
+    }
+}
+
+/// One way a password breaks a policy.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PolicyViolation {
+    /// The letter appears `count` times, outside `min..=max`.
+    Count { letter: char, count: usize, min: usize, max: usize },
+    /// The letter is at `both` of the two positions, or neither of them.
+    Positions { letter: char, first: usize, second: usize, both: bool },
+    /// A 1-based `position` that isn't in a password `length` characters long.
+    OutOfRange { position: usize, length: usize },
+    /// The password is `length` characters long, fewer than `min`.
+    TooShort { length: usize, min: usize },
+    /// The password has a `letter` in it.
+    Forbidden { letter: char },
+    /// The password doesn't match `pattern` anywhere.
+    NoMatch { pattern: String },
+    /// Each alternative of an [`AnyOf`] was broken, in these ways.
+    NoneOf(Vec<Vec<PolicyViolation>>),
+}
+
+impl fmt::Display for PolicyViolation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PolicyViolation::Count { letter, count, min, max } => {
+                write!(f, "`{}` appears {} times, not {} to {}", letter, count, min, max)
+            }
+            PolicyViolation::Positions { letter, first, second, both: true } => {
+                write!(f, "`{}` is at both positions {} and {}", letter, first, second)
+            }
+            PolicyViolation::Positions { letter, first, second, both: false } => {
+                write!(f, "`{}` is at neither position {} nor {}", letter, first, second)
+            }
+            PolicyViolation::OutOfRange { position: 0, .. } => {
+                write!(f, "positions count from 1, so there is no position 0")
+            }
+            PolicyViolation::OutOfRange { position, length } => {
+                write!(f, "position {} is past the end of a password {} characters long", position, length)
+            }
+            PolicyViolation::TooShort { length, min } => {
+                write!(f, "{} characters long, not at least {}", length, min)
+            }
+            PolicyViolation::Forbidden { letter } => write!(f, "contains `{}`", letter),
+            PolicyViolation::NoMatch { pattern } => write!(f, "doesn't match `{}`", pattern),
+            PolicyViolation::NoneOf(alternatives) => {
+                let alternatives: Vec<_> = alternatives.iter()
+                    .map(|violations| format!("({})", join(violations)))
+                    .collect();
+                write!(f, "none of {}", alternatives.join(" or "))
+            }
+        }
+    }
+}
+
+/// Lists violations on one line, separated by semicolons.
+pub fn join(violations: &[PolicyViolation]) -> String {
+    let violations: Vec<_> = violations.iter().map(ToString::to_string).collect();
+    violations.join("; ")
+}
+
+/// The old policy: the letter appears between `min` and `max` times.
+#[derive(Clone, Copy, Debug)]
+pub struct Count;
+
+impl Policy for Count {
+    fn violations(&self, password: &Password) -> Vec<PolicyViolation> {
+        let Password { letter, min, max, .. } = *password;
+        let count = password.password.chars().filter(|&c| c == letter).count();
+        if (min..=max).contains(&count) {
+            vec![]
+        } else {
+            vec![PolicyViolation::Count { letter, count, min, max }]
+        }
+    }
+}
+
+/// The Toboggan policy: the letter is at exactly one of the 1-based positions `min` and `max`.
+///
+/// Both positions have to be in the password, and each one that isn't is reported instead.
+#[derive(Clone, Copy, Debug)]
+pub struct Positions;
+
+impl Policy for Positions {
+    fn violations(&self, password: &Password) -> Vec<PolicyViolation> {
+        let Password { letter, min, max, .. } = *password;
+        let chars: Vec<char> = password.password.chars().collect();
+        let at = |position: usize| position.checked_sub(1).and_then(|index| chars.get(index));
+
+        let out_of_range: Vec<_> = [min, max].iter()
+            .filter(|&&position| at(position).is_none())
+            .map(|&position| PolicyViolation::OutOfRange { position, length: chars.len() })
+            .collect();
+        if !out_of_range.is_empty() {
+            return out_of_range;
+        }
+
+        let (at_first, at_second) = (at(min) == Some(&letter), at(max) == Some(&letter));
+        if at_first != at_second {
+            vec![]
+        } else {
+            vec![PolicyViolation::Positions { letter, first: min, second: max, both: at_first }]
+        }
+    }
+}
+
+/// The password has at least this many characters.
+#[derive(Clone, Copy, Debug)]
+pub struct MinLength(pub usize);
+
+impl Policy for MinLength {
+    fn violations(&self, password: &Password) -> Vec<PolicyViolation> {
+        let length = password.password.chars().count();
+        if length >= self.0 {
+            vec![]
+        } else {
+            vec![PolicyViolation::TooShort { length, min: self.0 }]
+        }
+    }
+}
+
+/// The password doesn't have this letter in it.
+#[derive(Clone, Copy, Debug)]
+pub struct Forbid(pub char);
+
+impl Policy for Forbid {
+    fn violations(&self, password: &Password) -> Vec<PolicyViolation> {
+        if password.password.contains(self.0) {
+            vec![PolicyViolation::Forbidden { letter: self.0 }]
+        } else {
+            vec![]
+        }
+    }
+}
+
+/// The password matches this regular expression somewhere; anchor it to match all of it.
+#[derive(Clone, Debug)]
+pub struct Matches(pub Regex);
+
+impl Policy for Matches {
+    fn violations(&self, password: &Password) -> Vec<PolicyViolation> {
+        if self.0.is_match(&password.password) {
+            vec![]
+        } else {
+            vec![PolicyViolation::NoMatch { pattern: self.0.as_str().to_owned() }]
+        }
+    }
+}
+
+/// Every one of these policies holds, breaking each of them in turn otherwise.
+#[derive(Debug)]
+pub struct AllOf(pub Vec<Box<dyn Policy>>);
+
+impl Policy for AllOf {
+    fn violations(&self, password: &Password) -> Vec<PolicyViolation> {
+        self.0.iter().flat_map(|policy| policy.violations(password)).collect()
+    }
+}
+
+/// At least one of these policies holds.
+#[derive(Debug)]
+pub struct AnyOf(pub Vec<Box<dyn Policy>>);
+
+impl Policy for AnyOf {
+    fn violations(&self, password: &Password) -> Vec<PolicyViolation> {
+        let mut alternatives = Vec::new();
+        for policy in &self.0 {
+            let violations = policy.violations(password);
+            if violations.is_empty() {
+                return vec![];
+            }
+            alternatives.push(violations);
+        }
+        vec![PolicyViolation::NoneOf(alternatives)]
+    }
+}
+
+/// How one line of a database fared against a policy.
+#[derive(Debug)]
+pub enum Outcome {
+    Valid(Password),
+    Invalid(Password, Vec<PolicyViolation>),
+    /// The line couldn't be read as a password and its policy.
+    Malformed(ParseError),
+}
+
+/// How many lines of a database were of each [`Outcome`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Summary {
+    pub valid: usize,
+    pub invalid: usize,
+    pub malformed: usize,
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} valid, {} invalid, {} malformed", self.valid, self.invalid, self.malformed)
+    }
+}
+
+/// Checks every line of `input` against `policy`, carrying on past lines that can't be read.
+pub fn check(input: &str, policy: &dyn Policy) -> (Vec<Outcome>, Summary) {
+    let mut summary = Summary::default();
+    let outcomes = input.lines()
+        .enumerate()
+        .map(|(index, line)| match line.parse::<Password>() {
+            Ok(password) => {
+                let violations = policy.violations(&password);
+                if violations.is_empty() {
+                    summary.valid += 1;
+                    Outcome::Valid(password)
+                } else {
+                    summary.invalid += 1;
+                    Outcome::Invalid(password, violations)
+                }
+            }
+            Err(e) => {
+                summary.malformed += 1;
+                Outcome::Malformed(e.on_line(index + 1))
+            }
+        })
+        .collect();
+    (outcomes, summary)
+}
+
+/// Reads a policy from its description, which is one of
+///
+/// - `count` or `positions`, the puzzle's two policies
+/// - `min-length=N`, `forbid=C` or `regex=PATTERN`
+/// - `all(...)` or `any(...)`, around a comma-separated list of other policies
+///
+/// Brackets in a regular expression have to balance, so that its commas can be told apart from
+/// those between policies.
+pub fn parse(description: &str) -> Result<Box<dyn Policy>, String> {
+    let description = description.trim();
+    let (name, argument) = match description.split_once('=') {
+        Some((name, argument)) if !name.contains('(') => (name.trim(), Some(argument)),
+        _ => (description, None),
+    };
+
+    match (name, argument) {
+        ("count", None) => Ok(Box::new(Count)),
+        ("positions", None) => Ok(Box::new(Positions)),
+        ("min-length", Some(length)) => match length.trim().parse() {
+            Ok(length) => Ok(Box::new(MinLength(length))),
+            Err(_) => Err(format!("expected a length, not `{}`", length)),
+        },
+        ("forbid", Some(letter)) => {
+            let mut chars = letter.trim().chars();
+            match (chars.next(), chars.next()) {
+                (Some(letter), None) => Ok(Box::new(Forbid(letter))),
+                _ => Err(format!("expected a single letter to forbid, not `{}`", letter)),
+            }
+        }
+        ("regex", Some(pattern)) => match Regex::new(pattern) {
+            Ok(regex) => Ok(Box::new(Matches(regex))),
+            Err(e) => Err(format!("invalid regular expression `{}`: {}", pattern, e)),
+        },
+        _ => {
+            if let Some(policies) = list(description, "all") {
+                Ok(Box::new(AllOf(policies?)))
+            } else if let Some(policies) = list(description, "any") {
+                Ok(Box::new(AnyOf(policies?)))
+            } else {
+                Err(format!("unknown policy `{}`", description))
+            }
+        }
+    }
+}
+
+/// The policies listed in `description` if it's of the form `combinator(...)`.
+fn list(description: &str, combinator: &str) -> Option<Result<Vec<Box<dyn Policy>>, String>> {
+    let inner = description.strip_prefix(combinator)?.trim_start().strip_prefix('(')?.strip_suffix(')')?;
+
+    let mut policies = Vec::new();
+    let (mut depth, mut start) = (0, 0);
+    for (i, c) in inner.char_indices() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            ',' if depth == 0 => {
+                policies.push(&inner[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    policies.push(&inner[start..]);
+    Some(policies.into_iter().map(parse).collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn password(line: &str) -> Password {
+        line.parse().unwrap()
+    }
+
+    #[test]
+    fn test_puzzle_policies() {
+        assert!(Count.validate(&password("1-3 a: abcde")));
+        assert_eq!(
+            vec![PolicyViolation::Count { letter: 'b', count: 0, min: 1, max: 3 }],
+            Count.violations(&password("1-3 b: cdefg")),
+        );
+        assert!(Positions.validate(&password("1-3 a: abcde")));
+        assert_eq!(
+            vec![PolicyViolation::Positions { letter: 'c', first: 2, second: 9, both: true }],
+            Positions.violations(&password("2-9 c: ccccccccc")),
+        );
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        assert_eq!(vec![
+            PolicyViolation::OutOfRange { position: 0, length: 5 },
+            PolicyViolation::OutOfRange { position: 6, length: 5 },
+        ], Positions.violations(&password("0-6 a: abcde")));
+        assert_eq!(
+            "position 6 is past the end of a password 5 characters long",
+            PolicyViolation::OutOfRange { position: 6, length: 5 }.to_string(),
+        );
+    }
+
+    /// Positions count characters, however many bytes they take.
+    #[test]
+    fn test_unicode() {
+        let password = password("1-2 é: ñéü");
+        assert!(Positions.validate(&password));
+        assert!(Count.validate(&password));
+        assert_eq!(1, Forbid('ü').violations(&password).len());
+    }
+
+    #[test]
+    fn test_check() {
+        let input = "1-3 a: abcde\n1-3 b: cdefg\n2-9 c ccccccccc\n2-9 c: cc\n";
+        let (outcomes, summary) = check(input, &Positions);
+        assert_eq!(Summary { valid: 1, invalid: 2, malformed: 1 }, summary);
+        assert!(matches!(&outcomes[2], Outcome::Malformed(e) if e.line == 3));
+        assert!(matches!(&outcomes[3], Outcome::Invalid(_, violations) if violations.len() == 1));
+        assert_eq!("1 valid, 2 invalid, 1 malformed", summary.to_string());
+    }
+
+    #[test]
+    fn test_combinators() {
+        let policy = parse("all(count, min-length=6, any(forbid=x, regex=^a{2,}), forbid = e)").unwrap();
+        assert!(policy.validate(&password("1-3 a: aabcdf")));
+        assert_eq!(vec![
+            PolicyViolation::TooShort { length: 5, min: 6 },
+            PolicyViolation::NoneOf(vec![
+                vec![PolicyViolation::Forbidden { letter: 'x' }],
+                vec![PolicyViolation::NoMatch { pattern: "^a{2,}".to_owned() }],
+            ]),
+            PolicyViolation::Forbidden { letter: 'e' },
+        ], policy.violations(&password("1-3 a: axcde")));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert!(parse("positions").is_ok());
+        assert!(parse(" any( count ,positions) ").is_ok());
+        assert_eq!("unknown policy `sideways`", parse("sideways").unwrap_err());
+        assert_eq!("unknown policy `all(count`", parse("all(count").unwrap_err());
+        assert!(parse("min-length=lots").is_err());
+        assert!(parse("forbid=xy").is_err());
+        assert!(parse("all(count, regex=[)").is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        let violation = PolicyViolation::NoneOf(vec![
+            vec![PolicyViolation::Forbidden { letter: 'x' }, PolicyViolation::TooShort { length: 2, min: 4 }],
+            vec![PolicyViolation::Positions { letter: 'a', first: 1, second: 2, both: false }],
+        ]);
+        assert_eq!(
+            "none of (contains `x`; 2 characters long, not at least 4) or (`a` is at neither position 1 nor 2)",
+            violation.to_string(),
+        );
+    }
+}
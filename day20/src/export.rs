@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+use std::io;
+
+use aoc_core::Part;
+use aoc_export::{Export, Frames, Rgb, Scene};
+
+use crate::{sea_monster, Day20, Image, RawImage, Tile};
+
+/// Pixels across each pixel of the satellite image.
+const SIZE: f64 = 6.0;
+
+const WATER: Rgb = Rgb(10, 40, 90);
+const WAVE: Rgb = Rgb(90, 160, 220);
+const MONSTER: Rgb = Rgb(60, 220, 90);
+
+impl Export for Day20 {
+    /// The assembled image, turned to show the sea monsters, with them picked out from the rest
+    /// of the waves. Both parts draw the same picture.
+    fn export(tiles: &HashMap<usize, Tile>, _part: Part, frames: &mut Frames) -> io::Result<()> {
+        let image = Image::from_raw_image(RawImage::from_tile_map(tiles), tiles);
+        let sea_monster = sea_monster();
+        let (image, monsters) = image.locate_sea_monsters(&sea_monster);
+
+        let mut colours = image.map(|&wave| if wave { WAVE } else { WATER });
+        for (x, y) in monsters {
+            for (dx, dy) in sea_monster.positions().filter(|&point| sea_monster[point]) {
+                colours[(x + dx, y + dy)] = MONSTER;
+            }
+        }
+
+        let mut scene = Scene::new(image.width() * SIZE as usize, image.height() * SIZE as usize, WATER);
+        scene.grid(&colours, (0.0, 0.0), SIZE, |&colour| Some(colour).filter(|&colour| colour != WATER));
+        frames.push(scene)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_export::Format;
+
+    use super::*;
+    use crate::{process_input, test::INPUT};
+
+    #[test]
+    fn test_export() {
+        let tiles = process_input(INPUT).unwrap();
+        let dir = std::env::temp_dir().join(format!("day20-export-test-{}", std::process::id()));
+        let mut frames = Frames::sequence(&dir, Format::Svg).unwrap();
+        Day20::export(&tiles, Part::Two, &mut frames).unwrap();
+        assert_eq!(1, frames.finish().unwrap());
+
+        let svg = std::fs::read_to_string(dir.join("frame-0000.svg")).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="144" height="144""#));
+        // Two sea monsters of 15 pixels each.
+        assert_eq!(30, svg.matches(&format!(r#"fill="{}""#, MONSTER)).count());
+    }
+}
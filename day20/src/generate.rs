@@ -0,0 +1,129 @@
+use std::collections::HashSet;
+
+use aoc_core::generate::Generated;
+use aoc_grid::{Grid, Point};
+use rand::{seq::SliceRandom, Rng};
+
+use crate::SEA_MONSTER;
+
+/// Number of tiles along each side of a generated image.
+pub const DEFAULT_SIZE: usize = 12;
+
+/// Tiles for a `size` by `size` image, shuffled and each turned and flipped at random.
+///
+/// The image is drawn first, with sea monsters planted in rough water, and then cut up. The
+/// tile edges are drawn so that no two of them match unless they meet in the image, and none
+/// reads the same both ways, so the tiles only fit together one way. `size` is from 3 to 14, as
+/// larger images run out of distinct edges.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+    let side = size.clamp(3, 14);
+    let monster: Vec<Point> = SEA_MONSTER.lines().enumerate()
+        .flat_map(|(y, line)| line.char_indices().filter(|(_, c)| *c == '#').map(move |(x, _)| (x, y)))
+        .collect();
+
+    let (image, monsters) = loop {
+        let (image, planted) = draw_image(rng, 8 * side, &monster);
+        if count_monsters(&image, &monster) == planted
+            && image.orientations().all(|image| count_monsters(&image, &monster) <= planted)
+        {
+            break (image, planted);
+        }
+    };
+    let waves = image.iter().filter(|&&wave| wave).count() - monsters * monster.len();
+
+    let sheet = loop {
+        if let Some(sheet) = draw_sheet(rng, &image, side) {
+            break sheet;
+        }
+    };
+
+    let mut ids: Vec<usize> = (1000..10000).collect();
+    ids.shuffle(rng);
+    let mut tiles = Vec::new();
+    for y in 0..side {
+        for x in 0..side {
+            let mut tile = Grid::from_fn(10, 10, |(dx, dy)| sheet[(9 * x + dx, 9 * y + dy)]);
+            for _ in 0..rng.gen_range(0..4) {
+                tile = tile.rotate();
+            }
+            if rng.gen_bool(0.5) {
+                tile = tile.flip_horizontal();
+            }
+            tiles.push(format!("Tile {}:\n{}", ids[y * side + x], tile));
+        }
+    }
+    let corners = [0, side - 1, side * (side - 1), side * side - 1].iter().map(|&i| ids[i]).product::<usize>();
+    tiles.shuffle(rng);
+
+    Generated::new(tiles.join("\n"), Some(corners.to_string()), Some(waves.to_string()))
+}
+
+/// Rough water with sea monsters planted in it, none of them overlapping, and how many.
+fn draw_image<R: Rng + ?Sized>(rng: &mut R, pixels: usize, monster: &[Point]) -> (Grid<bool>, usize) {
+    let mut image = Grid::from_fn(pixels, pixels, |_| rng.gen_bool(0.3));
+    let mut taken = Grid::new(pixels, pixels, false);
+    let mut planted = 0;
+    for _ in 0..rng.gen_range(1..=pixels * pixels / 200) {
+        let (x, y) = (rng.gen_range(0..=pixels - 20), rng.gen_range(0..=pixels - 3));
+        let area: Vec<Point> = (0..20).flat_map(|dx| (0..3).map(move |dy| (x + dx, y + dy))).collect();
+        if area.iter().all(|&point| !taken[point]) {
+            area.iter().for_each(|&point| taken[point] = true);
+            monster.iter().for_each(|&(dx, dy)| image[(x + dx, y + dy)] = true);
+            planted += 1;
+        }
+    }
+    (image, planted)
+}
+
+fn count_monsters(image: &Grid<bool>, monster: &[Point]) -> usize {
+    image.positions()
+        .filter(|&(x, y)| monster.iter().all(|&(dx, dy)| image.get((x + dx, y + dy)) == Some(&true)))
+        .count()
+}
+
+/// The whole image with the tile borders drawn in, each border shared by the two tiles it
+/// separates, or `None` if the edges ran out of distinct values.
+fn draw_sheet<R: Rng + ?Sized>(rng: &mut R, image: &Grid<bool>, side: usize) -> Option<Grid<bool>> {
+    let n = 9 * side + 1;
+    let mut sheet = Grid::from_fn(n, n, |(x, y)| {
+        if x % 9 == 0 || y % 9 == 0 {
+            rng.gen_bool(0.5)
+        } else {
+            image[(x / 9 * 8 + x % 9 - 1, y / 9 * 8 + y % 9 - 1)]
+        }
+    });
+
+    // Edges are told apart by the smaller of their value and its reverse.
+    let mut used = HashSet::new();
+    let starts = (0..=side).flat_map(|a| (0..side).flat_map(move |b| vec![((9 * b, 9 * a), (1, 0)), ((9 * a, 9 * b), (0, 1))]));
+    for ((x, y), (dx, dy)) in starts.collect::<Vec<_>>() {
+        let points: Vec<Point> = (0..10).map(|i| (x + i * dx, y + i * dy)).collect();
+        let drawn = (0..1000).any(|_| {
+            for &point in &points[1..9] {
+                sheet[point] = rng.gen_bool(0.5);
+            }
+            let bits: Vec<bool> = points.iter().map(|&point| sheet[point]).collect();
+            let reversed: Vec<bool> = bits.iter().rev().copied().collect();
+            bits != reversed && used.insert(bits.clone().min(reversed))
+        });
+        if !drawn {
+            return None;
+        }
+    }
+    Some(sheet)
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day20;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..5 {
+            generate(&mut StdRng::seed_from_u64(seed), 4).assert_solves::<Day20>();
+        }
+    }
+}
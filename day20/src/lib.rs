@@ -0,0 +1,517 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::{diag, parse, ParseError, Solver};
+use aoc_grid::{Grid, Point};
+
+#[cfg(feature = "export")]
+pub mod export;
+#[cfg(feature = "generate")]
+pub mod generate;
+
+const SEA_MONSTER: &str =
+"                  # 
+#    ##    ##    ###
+ #  #  #  #  #  #   ";
+
+/// Day 20: Jurassic Jigsaw
+pub struct Day20;
+
+impl Solver for Day20 {
+    const DAY: u8 = 20;
+
+    type Input<'a> = HashMap<usize, Tile>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        process_input(input)
+    }
+
+    fn part1(tiles: &Self::Input<'_>) -> usize {
+        get_answer(tiles)
+    }
+
+    fn part2(tiles: &Self::Input<'_>) -> usize {
+        get_answer2(tiles)
+    }
+}
+
+fn process_input(input: &str) -> Result<HashMap<usize, Tile>, ParseError> {
+    parse::blocks(input)
+        .filter(|(_, s)| !s.is_empty())
+        .map(|(offset, s)| Tile::parse(s).map_err(|e| e.shift_lines(offset)))
+        .collect()
+}
+
+fn get_answer(tiles: &HashMap<usize, Tile>) -> usize {
+    let edge_map = EdgeMap::from_tile_map(tiles);
+
+    edge_map.corner_ids().product()
+
+}
+
+fn get_answer2(tiles: &HashMap<usize, Tile>) -> usize {
+    let raw_image = RawImage::from_tile_map(tiles);
+    let image = Image::from_raw_image(raw_image, tiles);
+    diag!(2, "{}", image);
+
+    let sea_monster = sea_monster();
+    let monster_size = sea_monster.iter().filter(|b| **b).count();
+
+    let count = image.find_sea_monsters(&sea_monster);
+    let waves = image.count_waves();
+    diag!(1, "{} sea monsters among {} waves", count, waves);
+    waves - count * monster_size
+}
+
+/// The sea monster pattern, where `true` is part of the monster.
+pub fn sea_monster() -> Grid<bool> {
+    Grid::parse_with(SEA_MONSTER, "`#` or ` `", |c| match c {
+        '#' => Some(true),
+        ' ' => Some(false),
+        _ => None,
+    }).expect("sea monster pattern is a valid grid")
+}
+
+/// A 10x10 piece of the satellite image.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Tile(Grid<bool>);
+
+impl Tile {
+    /// Parses a tile block; errors count lines from the `Tile N:` header.
+    pub fn parse(input: &str) -> Result<(usize, Self), ParseError> {
+        let (header, body) = input.split_once('\n').unwrap_or((input, ""));
+        let id = header.strip_prefix("Tile ")
+            .and_then(|s| s.strip_suffix(':'))
+            .ok_or_else(|| ParseError::at(header, header, "`Tile <id>:`"))?;
+        let id = parse::number(header, id)?;
+
+        let image = Grid::parse(body).map_err(|e| e.shift_lines(1))?;
+        if image.width() != 10 || image.height() != 10 {
+            let found = format!("{}x{}", image.width(), image.height());
+            return Err(ParseError::new(1, "a 10x10 tile", found));
+        }
+
+        Ok((id, Tile(image)))
+    }
+
+    /// The top, right, bottom and left edges, each read clockwise around the tile.
+    pub fn edges(&self) -> impl Iterator<Item=Edge> {
+        vec![
+            Edge::from_iter((0..10).map(|i| self.0[(i, 0)])),
+            Edge::from_iter((0..10).map(|i| self.0[(9, i)])),
+            Edge::from_iter((0..10).map(|i| self.0[(i, 9)])).reverse(),
+            Edge::from_iter((0..10).map(|i| self.0[(0, i)])).reverse(),
+        ].into_iter()
+    }
+
+    /// The pixel at `(x, y)` once the tile is mirrored if `flipped`, then given `rotation`
+    /// quarter turns.
+    pub fn index(&self, flipped: bool, rotation: u8, x: usize, y: usize) -> bool {
+        if flipped {
+            match rotation {
+                0 => self.0[(9-x, y)],
+                1 => self.0[(9-y, 9-x)],
+                2 => self.0[(x, 9-y)],
+                3 => self.0[(y, x)],
+                _ => panic!(),
+            }
+        } else {
+            match rotation {
+                0 => self.0[(x, y)],
+                1 => self.0[(y, 9-x)],
+                2 => self.0[(9-x, 9-y)],
+                3 => self.0[(9-y, x)],
+                _ => panic!(),
+            }
+        }
+    }
+}
+
+/// The ten pixels along one side of a tile, as bits.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Edge(pub u16);
+
+impl Edge {
+    /// Reads pixels in order, most significant bit first.
+    pub fn from_iter<I: IntoIterator<Item=bool>>(iter: I) -> Self {
+        let mut edge = 0;        
+        for bit in iter {
+            edge <<= 1;
+            if bit {edge += 1}
+        }
+        Edge(edge)
+    }
+
+    /// The same edge read from the other end.
+    pub fn reverse(mut self) -> Self {
+        let mut edge = 0;
+        for _ in 0..10 {
+            edge <<= 1;
+            if 1 == (1 & self.0) {
+                edge += 1;
+            }
+            self.0 >>= 1;
+        }
+        Edge(edge)
+    }
+}
+
+/// Every edge of every tile, with the `(id, flipped, rotation)` of each tile it appears on.
+#[derive(Debug, Default)]
+pub struct EdgeMap(HashMap<Edge, HashSet<(usize, bool, u8)>>);
+
+impl EdgeMap {
+    pub fn from_tile_map(tiles: &HashMap<usize, Tile>) -> Self {
+        let mut edge_map = EdgeMap::default();
+        for (id, tile) in tiles.iter() {
+            edge_map.insert(*id, tile.edges())
+        }
+        edge_map
+    }
+
+    fn insert(&mut self, id: usize, edges: impl Iterator<Item=Edge>) {
+        for (rotation, edge) in edges.enumerate() {
+            let rot = rotation as u8;
+            self.insert_one(edge, (id, false, rot));
+            self.insert_one(edge.reverse(), (id, true, rot));
+        }
+    }
+
+    fn insert_one(&mut self, edge:Edge, value: (usize, bool, u8)) {
+        if let Some(hashset) = self.0.get_mut(&edge) {
+            hashset.insert(value);
+        } else {
+            self.0.insert(edge, Some(value).into_iter().collect());
+        }
+    }
+
+    /// The tiles with two edges that match no other tile.
+    pub fn corner_ids(&self) -> impl Iterator<Item = usize> {
+        let mut counts: HashMap<usize, usize> = HashMap::new();
+
+        for (_, ids) in self.0.iter().filter(|(_, ids)| ids.len() == 1) {
+            let (id, _, _) = ids.iter().next().unwrap();
+            if let Some(count) = counts.get_mut(id) {
+                *count += 1;
+            } else {
+                counts.insert(*id, 1);
+            }
+        }
+    
+        counts.into_iter().filter(|(_id, count)| *count == 4).map(|(id, _)| id)
+    }
+}
+
+/// The tiles laid out in place, as the `(id, flipped, rotation)` of each.
+#[derive(Debug)]
+pub struct RawImage(Vec<Vec<Option<(usize, bool, u8)>>>);
+
+impl RawImage {
+    /// Fits the tiles together, starting from a corner and working along each row.
+    pub fn from_tile_map(tiles: &HashMap<usize, Tile>) -> Self {
+        let side_len = (tiles.len() as f64).sqrt() as usize;
+
+        let mut raw_image = vec![vec![None; side_len]; side_len];
+
+        let edge_map = EdgeMap::from_tile_map(tiles);
+        let corner_id = edge_map.corner_ids().next().unwrap();
+
+        let edges: Vec<_> = tiles.get(&corner_id).unwrap().edges().collect();
+        let edge0_matches = edge_map.0.get(&edges[0]).unwrap().len();
+        let edge1_matches = edge_map.0.get(&edges[1]).unwrap().len();
+        
+        let corner_rotation = match (edge0_matches == 2, edge1_matches == 2) {
+            (true, true) => 1,
+            (false, true) => 0,
+            (false, false) => 3,
+            (true, false) => 2,
+        };
+
+        raw_image[0][0] = Some((corner_id, false, corner_rotation));
+        
+        for i in 1..side_len {
+            let (old_id, old_flipped, old_rotation) = raw_image[i-1][0].unwrap();
+
+            let old_edge_rotation = if old_flipped {
+                (2 + old_rotation as usize) % 4
+            } else {
+                (4 + 2 - old_rotation as usize) % 4
+            };
+
+            let old_edge = tiles.get(&old_id).unwrap().edges().nth(old_edge_rotation).unwrap();
+            let (new_id, edge_flipped, new_edge_rotation) =
+                edge_map.0.get(&old_edge).unwrap().iter().find(|(id, _, _)| *id != old_id).unwrap();
+            // Two unflipped tiles have edges that run opposite one another
+            let new_flipped = !(old_flipped ^ edge_flipped);
+
+            let new_rotation = if new_flipped {
+                *new_edge_rotation % 4
+            } else {
+                (4 - new_edge_rotation) % 4
+            };
+
+            raw_image[i][0] = Some((*new_id, new_flipped, new_rotation));
+        }
+
+        for row in raw_image.iter_mut() {
+            let (mut old_id, mut old_flipped, mut old_rotation) = row[0].unwrap();
+
+            for tile in row.iter_mut().skip(1) {
+                let old_edge_rotation = if old_flipped {
+                    (3 + old_rotation as usize) % 4
+                } else {
+                    (4 + 1 - old_rotation as usize) % 4
+                };
+    
+                let old_edge = tiles.get(&old_id).unwrap().edges().nth(old_edge_rotation).unwrap();
+                let (new_id, edge_flipped, new_edge_rotation) =
+                    edge_map.0.get(&old_edge).unwrap().iter().find(|(id, _, _)| *id != old_id).unwrap();
+                // Two unflipped tiles have edges that run opposite one another
+                let new_flipped = !(old_flipped ^ edge_flipped);
+    
+                let new_rotation = if new_flipped {
+                    (3 + new_edge_rotation) % 4
+                } else {
+                    (4 + 3 - new_edge_rotation) % 4
+                };
+
+                *tile = Some((*new_id, new_flipped, new_rotation));
+                old_id = *new_id;
+                old_flipped = new_flipped;
+                old_rotation = new_rotation;
+            }
+        }
+
+        RawImage(raw_image)
+    }
+}
+
+/// The assembled image, with the tile borders removed.
+pub struct Image(Grid<bool>);
+
+impl Image {
+    pub fn from_raw_image(raw_image: RawImage, tiles: &HashMap<usize, Tile>) -> Self {
+        let side_len = raw_image.0.len() * 8;
+
+        Image(Grid::from_fn(side_len, side_len, |(x_index, y_index)| {
+            let x_0 = x_index / 8;
+            let x_1 = x_index % 8 + 1;
+            let y_0 = y_index / 8;
+            let y_1 = y_index % 8 + 1;
+
+            let (id, flipped, rotation) = raw_image.0[y_0][x_0].unwrap();
+            let tile = tiles.get(&id).unwrap();
+            tile.index(flipped, rotation, x_1, y_1)
+        }))
+    }
+
+    /// The number of `#` pixels.
+    pub fn count_waves(&self) -> usize {
+        self.0.iter().filter(|b| **b).count()
+    }
+
+    /// Counts the sea monsters in whichever orientation of the image shows the most of them.
+    pub fn find_sea_monsters(&self, sea_monster: &Grid<bool>) -> usize {
+        self.locate_sea_monsters(sea_monster).1.len()
+    }
+
+    /// The orientation of the image that shows the most sea monsters, with the top left corner
+    /// of each of them.
+    pub fn locate_sea_monsters(&self, sea_monster: &Grid<bool>) -> (Grid<bool>, Vec<Point>) {
+        self.0.orientations().map(|image| {
+            let monsters = image.positions()
+                .filter(|(x, y)| contains_monster_at_coords(&image, sea_monster, *x, *y))
+                .collect();
+            (image, monsters)
+        }).max_by_key(|(_, monsters): &(_, Vec<_>)| monsters.len()).expect("an image has eight orientations")
+    }
+}
+
+fn contains_monster_at_coords(image: &Grid<bool>, sea_monster: &Grid<bool>, x: usize, y: usize) -> bool {
+    sea_monster.positions()
+        .filter(|point| sea_monster[*point])
+        .all(|(sm_x, sm_y)| image.get((sm_x + x, sm_y + y)) == Some(&true))
+}
+
+impl std::fmt::Display for Image {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    /// Any ten pixels, shrinking towards all `.`.
+    fn edge_pixels() -> impl Strategy<Value=Vec<bool>> {
+        vec(any::<bool>(), 10)
+    }
+
+    #[test]
+    fn test_reverse() {
+        assert_eq!(Edge(210), Edge(300).reverse());
+    }
+
+    proptest! {
+        #[test]
+        fn reverse_is_an_involution(pixels in edge_pixels()) {
+            let edge = Edge::from_iter(pixels);
+            prop_assert_eq!(edge, edge.reverse().reverse());
+        }
+
+        #[test]
+        fn reverse_reads_from_the_other_end(pixels in edge_pixels()) {
+            let reversed = Edge::from_iter(pixels.iter().rev().copied());
+            prop_assert_eq!(reversed, Edge::from_iter(pixels).reverse());
+        }
+    }
+
+    #[test]
+    fn test_edge() {
+        let tiles =  process_input(INPUT).unwrap();
+        let tile = tiles.get(&2311).unwrap();
+        let edge = tile.edges().next().unwrap();
+        assert_eq!(Edge(210), edge);
+    }
+
+    #[test]
+    fn test_answer() {
+        let tiles = process_input(INPUT).unwrap();
+        assert_eq!(20899048083289, get_answer(&tiles));
+    }
+
+    #[test]
+    fn test_answer2() {
+        let tiles = process_input(INPUT).unwrap();
+        assert_eq!(273, get_answer2(&tiles));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = process_input(&INPUT.replacen("Tile 1951:", "Tile #1951:", 1)).err();
+        assert_eq!(Some(ParseError::new(6, "a number", "#1951").on_line(13)), error);
+
+        let error = process_input(&INPUT.replacen("##..#.....", "##..#..o..", 1)).err();
+        assert_eq!(Some(ParseError::new(8, "`#` or `.`", "o").on_line(3)), error);
+
+        let error = process_input(&INPUT.replacen("##..#.....", "##..#....", 1)).err();
+        assert_eq!(Some(ParseError::new(1, "a row of 10 cells", "##..#....").on_line(3)), error);
+
+        let error = process_input("Tile 1:\n..........").err();
+        assert_eq!(Some(ParseError::new(1, "a 10x10 tile", "10x1")), error);
+    }
+
+
+    pub(crate) const INPUT: &str = "\
+Tile 2311:
+..##.#..#.
+##..#.....
+#...##..#.
+####.#...#
+##.##.###.
+##...#.###
+.#.#.#..##
+..#....#..
+###...#.#.
+..###..###
+
+Tile 1951:
+#.##...##.
+#.####...#
+.....#..##
+#...######
+.##.#....#
+.###.#####
+###.##.##.
+.###....#.
+..#.#..#.#
+#...##.#..
+
+Tile 1171:
+####...##.
+#..##.#..#
+##.#..#.#.
+.###.####.
+..###.####
+.##....##.
+.#...####.
+#.##.####.
+####..#...
+.....##...
+
+Tile 1427:
+###.##.#..
+.#..#.##..
+.#.##.#..#
+#.#.#.##.#
+....#...##
+...##..##.
+...#.#####
+.#.####.#.
+..#..###.#
+..##.#..#.
+
+Tile 1489:
+##.#.#....
+..##...#..
+.##..##...
+..#...#...
+#####...#.
+#..#.#.#.#
+...#.#.#..
+##.#...##.
+..##.##.##
+###.##.#..
+
+Tile 2473:
+#....####.
+#..#.##...
+#.##..#...
+######.#.#
+.#...#.#.#
+.#########
+.###.#..#.
+########.#
+##...##.#.
+..###.#.#.
+
+Tile 2971:
+..#.#....#
+#...###...
+#.#.###...
+##.##..#..
+.#####..##
+.#..####.#
+#..#.#..#.
+..####.###
+..#.#.###.
+...#.#.#.#
+
+Tile 2729:
+...#.#.#.#
+####.#....
+..#.#.....
+....#..#.#
+.##..##.#.
+.#.####...
+####.#.#..
+##.####...
+##..#.##..
+#.##...##.
+
+Tile 3079:
+#.#.#####.
+.#..######
+..#.......
+######....
+####.#..#.
+.#...#.##.
+#.#####.##
+..#.###...
+..#.......
+..#.###...";
+}
@@ -0,0 +1,79 @@
+use std::collections::HashSet;
+
+use aoc_core::generate::Generated;
+use rand::{seq::{index, SliceRandom}, Rng};
+
+/// Number of foods in a generated list.
+pub const DEFAULT_SIZE: usize = 40;
+
+const ALLERGENS: [&str; 9] = ["dairy", "eggs", "fish", "nuts", "peanuts", "sesame", "shellfish", "soy", "wheat"];
+
+/// A list of `size` foods, each listing one to three of eight allergens, with 200 ingredients
+/// between them.
+///
+/// The allergens are ranked and every food that lists the allergen of rank `k` has the
+/// ingredients of ranks 0 to `k` in it. The lists are redrawn until those are exactly the
+/// ingredients each allergen could be in, so the allergens can be worked out one rank at a time.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+    let size = size.max(8);
+    let mut ingredients = HashSet::new();
+    while ingredients.len() < 200 {
+        let len = rng.gen_range(4..=8);
+        ingredients.insert((0..len).map(|_| rng.gen_range(b'a'..=b'z') as char).collect::<String>());
+    }
+    let ingredients: Vec<String> = ingredients.into_iter().collect();
+    let allergens: Vec<&str> = ALLERGENS.choose_multiple(rng, 8).copied().collect();
+    let ranks = allergens.len();
+
+    let foods = loop {
+        let foods: Vec<(Vec<usize>, Vec<usize>)> = (0..size).map(|_| {
+            let count = rng.gen_range(1..=3);
+            let listed: Vec<usize> = index::sample(rng, ranks, count).into_vec();
+            let highest = *listed.iter().max().unwrap();
+            let mut has: Vec<usize> = (0..ingredients.len())
+                .filter(|&i| i <= highest || rng.gen_bool(if i < ranks { 0.3 } else { 0.1 }))
+                .collect();
+            has.shuffle(rng);
+            (has, listed)
+        }).collect();
+
+        let exact = (0..ranks).all(|rank| {
+            let mut listing = foods.iter().filter(|(_, listed)| listed.contains(&rank));
+            let first: Option<HashSet<usize>> = listing.next().map(|(has, _)| has.iter().copied().collect());
+            let could = listing.fold(first, |could, (has, _)| could.map(|could| {
+                could.into_iter().filter(|i| has.contains(i)).collect()
+            }));
+            could == Some((0..=rank).collect())
+        });
+        if exact {
+            break foods;
+        }
+    };
+
+    let safe = foods.iter().map(|(has, _)| has.iter().filter(|&&i| i >= ranks).count()).sum::<usize>();
+    let mut by_name: Vec<(&str, &str)> = allergens.iter().zip(&ingredients).map(|(&a, i)| (a, i.as_str())).collect();
+    by_name.sort();
+    let dangerous: String = by_name.iter().map(|(_, ingredient)| format!("{},", ingredient)).collect();
+
+    let input = foods.iter().map(|(has, listed)| {
+        let has: Vec<&str> = has.iter().map(|&i| ingredients[i].as_str()).collect();
+        let listed: Vec<&str> = listed.iter().map(|&rank| allergens[rank]).collect();
+        format!("{} (contains {})\n", has.join(" "), listed.join(", "))
+    }).collect();
+    Generated::new(input, Some(safe.to_string()), Some(dangerous))
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day21;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            generate(&mut StdRng::seed_from_u64(seed), 40).assert_solves::<Day21>();
+        }
+    }
+}
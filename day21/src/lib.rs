@@ -0,0 +1,167 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::{parse, ParseError, Solver};
+use aoc_nom::{comma_list, tag, IResult};
+use nom::{
+    character::complete::{alpha1, char},
+    multi::many1,
+    sequence::{delimited, pair, terminated},
+};
+
+#[cfg(feature = "generate")]
+pub mod generate;
+
+/// Day 21: Allergen Assessment
+pub struct Day21;
+
+impl Solver for Day21 {
+    const DAY: u8 = 21;
+
+    type Input<'a> = Vec<Food<'a>>;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        process_input(input)
+    }
+
+    fn part1(food: &Self::Input<'_>) -> usize {
+        get_answer(food)
+    }
+
+    fn part2(food: &Self::Input<'_>) -> String {
+        get_answer2(food)
+    }
+}
+
+fn process_input(input: &str) -> Result<Vec<Food<'_>>, ParseError> {
+    parse::lines(input, |line| aoc_nom::finish(line, parse_food))
+}
+
+fn parse_food(i: &str) -> IResult<'_, Food<'_>> {
+    let (i, (ingredients, allergens)) = pair(
+        many1(terminated(alpha1, char(' '))),
+        delimited(tag("(contains "), comma_list(alpha1), char(')')),
+    )(i)?;
+
+    Ok((i, Food {
+        ingredients: ingredients.into_iter().collect(),
+        allergens: allergens.into_iter().collect(),
+    }))
+}
+
+fn get_answer(food: &[Food]) -> usize {
+    let all_ingredients = Food::all_ingredients(food);
+    
+    let allergen_map = Food::get_allergen_map(food);
+    
+    let bad_ingredients = allergen_map.values().flat_map(HashSet::iter).cloned().collect();
+    let good_ingredients: HashSet<_> = all_ingredients.difference(&bad_ingredients).collect();
+
+    let mut count = 0;
+    for Food {ingredients, allergens: _} in food {
+        count += ingredients.iter().filter(|i| good_ingredients.contains(i)).count();
+    }
+    count
+}
+
+fn get_answer2(food: &[Food]) -> String {
+
+    let allergen_map = Food::get_allergen_map(food);
+    let mut allergen_map: Vec<_> = allergen_map.into_iter().collect();
+
+    for index in 1..allergen_map.len() {
+        allergen_map.sort_by_key(|(_allergen, ingredients)| ingredients.len());
+
+        let (single_ingredients, multi_ingredients) = allergen_map.split_at_mut(index);
+
+        if let Some(current_ingredient) = single_ingredients.last().unwrap().1.iter().next() {
+            for ingredients in multi_ingredients {
+                ingredients.1.retain(|i| i != current_ingredient);
+            }
+        }
+    }
+
+    allergen_map.sort_by_key(|(allergen, _ingredients)| *allergen);
+
+    let mut display = String::new();
+    for (_allergen, ingredients) in allergen_map {
+        let ingredient = ingredients.iter().next().unwrap();
+        display.push_str(ingredient);
+        display.push(',');
+    }
+
+    display
+}
+
+#[derive(Clone, Debug)]
+pub struct Food<'a> {
+    pub ingredients: HashSet<&'a str>,
+    /// The allergens listed, which might not be all of them.
+    pub allergens: HashSet<&'a str>,
+}
+
+impl<'a> Food<'a> {
+    pub fn all_ingredients(food: &[Self]) -> HashSet<&'a str> {
+        food.iter().flat_map(|food| {
+            food.ingredients.iter()
+        //We are cloning a `&&str` to make a `&str`
+        }).cloned().collect()
+    }
+
+    pub fn all_allergens(food: &[Self]) -> HashSet<&'a str> {
+        food.iter().flat_map(|food| {
+            food.allergens.iter()
+        //We are cloning a `&&str` to make a `&str`
+        }).cloned().collect()
+    }
+
+    /// For each allergen, the ingredients that could contain it.
+    pub fn get_allergen_map(food: &[Self]) -> HashMap<&str, HashSet<&str>> {
+        let all_allergens = Food::all_allergens(food);
+        let all_ingredients = Food::all_ingredients(food);
+        
+        all_allergens.iter().map(|current_allergen| {
+            let mut current_ingredients = all_ingredients.clone();
+    
+            for Food {ingredients, allergens} in food {
+                if allergens.contains(current_allergen) {
+                    current_ingredients.retain(|i| ingredients.contains(i));
+                }
+            }
+    
+            (*current_allergen, current_ingredients)
+        }).collect()
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_answer() {
+        let food = process_input(INPUT).unwrap();
+        assert_eq!(5, get_answer(&food));
+    }
+
+    #[test]
+    fn test_answer2() {
+        let food = process_input(INPUT).unwrap();
+        assert_eq!("mxmxvkd,sqjhc,fvjkl,", get_answer2(&food));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = process_input("sqjhc fvjkl (contains soy\n").err();
+        assert_eq!(Some(ParseError::new(26, "`)`", "")), error);
+    }
+
+    const INPUT: &str = "\
+mxmxvkd kfcds sqjhc nhms (contains dairy, fish)
+trh fvjkl sbzzf mxmxvkd (contains dairy)
+sqjhc fvjkl (contains soy)
+sqjhc mxmxvkd sbzzf (contains fish)";
+
+}
@@ -0,0 +1,83 @@
+use std::collections::{HashSet, VecDeque};
+
+use aoc_core::generate::Generated;
+use rand::{seq::SliceRandom, Rng};
+
+/// Number of cards each player is dealt.
+pub const DEFAULT_SIZE: usize = 25;
+
+/// Two decks of `size` cards each, dealt from a shuffled deck numbered from 1.
+///
+/// Deals on which plain Combat would never end are thrown away. The answers are worked out by
+/// playing both games.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+    let size = size.max(1);
+    let (decks, part1) = loop {
+        let mut cards: Vec<usize> = (1..=2 * size).collect();
+        cards.shuffle(rng);
+        let decks: [VecDeque<usize>; 2] = [cards[..size].iter().copied().collect(), cards[size..].iter().copied().collect()];
+        if let Some(score) = combat(decks.clone()) {
+            break (decks, score);
+        }
+    };
+
+    let mut played = decks.clone();
+    let winner = recursive_combat(&mut played);
+    let part2 = score(&played[winner]);
+
+    let list = |deck: &VecDeque<usize>| deck.iter().map(|card| format!("{}\n", card)).collect::<String>();
+    let input = format!("Player 1:\n{}\nPlayer 2:\n{}", list(&decks[0]), list(&decks[1]));
+    Generated::new(input, Some(part1.to_string()), Some(part2.to_string()))
+}
+
+fn score(deck: &VecDeque<usize>) -> usize {
+    deck.iter().rev().zip(1..).map(|(card, place)| card * place).sum()
+}
+
+/// The winner's score, or `None` if the game goes round in circles.
+fn combat(mut decks: [VecDeque<usize>; 2]) -> Option<usize> {
+    let mut seen = HashSet::new();
+    while decks.iter().all(|deck| !deck.is_empty()) {
+        if !seen.insert(decks.clone()) {
+            return None;
+        }
+        let cards = [decks[0].pop_front().unwrap(), decks[1].pop_front().unwrap()];
+        let winner = (cards[1] > cards[0]) as usize;
+        decks[winner].extend([cards[winner], cards[1 - winner]].iter());
+    }
+    Some(decks.iter().map(score).sum())
+}
+
+/// Plays a game of Recursive Combat on `decks` and returns the winner, 0 or 1.
+fn recursive_combat(decks: &mut [VecDeque<usize>; 2]) -> usize {
+    let mut seen = HashSet::new();
+    while decks.iter().all(|deck| !deck.is_empty()) {
+        if !seen.insert(decks.clone()) {
+            return 0;
+        }
+        let cards = [decks[0].pop_front().unwrap(), decks[1].pop_front().unwrap()];
+        let winner = if decks[0].len() >= cards[0] && decks[1].len() >= cards[1] {
+            let mut sub = [decks[0].iter().take(cards[0]).copied().collect(), decks[1].iter().take(cards[1]).copied().collect()];
+            recursive_combat(&mut sub)
+        } else {
+            (cards[1] > cards[0]) as usize
+        };
+        decks[winner].extend([cards[winner], cards[1 - winner]].iter());
+    }
+    decks[0].is_empty() as usize
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day22;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            generate(&mut StdRng::seed_from_u64(seed), 10).assert_solves::<Day22>();
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use aoc_core::generate::Generated;
+use rand::{seq::SliceRandom, Rng};
+
+/// Number of cups in a generated circle.
+pub const DEFAULT_SIZE: usize = 9;
+
+/// A circle of `size` cups in a random order, where `size` is from 5 to 9 so every label is a
+/// single digit.
+///
+/// Part 1 is worked out by playing the 100 moves on a plain list; part 2 is left to the solver,
+/// as it takes ten million moves over a million cups.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+    let count = size.clamp(5, 9);
+    let mut cups: Vec<usize> = (1..=count).collect();
+    cups.shuffle(rng);
+    let input = cups.iter().map(usize::to_string).collect::<String>() + "\n";
+
+    // The current cup is always kept at the front.
+    let mut circle = cups;
+    for _ in 0..100 {
+        let picked: Vec<usize> = circle.drain(1..4).collect();
+        let mut destination = circle[0];
+        loop {
+            destination = if destination == 1 { count } else { destination - 1 };
+            if !picked.contains(&destination) {
+                break;
+            }
+        }
+        let at = circle.iter().position(|&cup| cup == destination).unwrap() + 1;
+        circle.splice(at..at, picked);
+        circle.rotate_left(1);
+    }
+    let one = circle.iter().position(|&cup| cup == 1).unwrap();
+    circle.rotate_left(one);
+    let part1: String = circle[1..].iter().map(usize::to_string).collect();
+
+    Generated::new(input, Some(part1), None)
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day23;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            generate(&mut StdRng::seed_from_u64(seed), 9).assert_solves::<Day23>();
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use std::f64::consts::PI;
+use std::io;
+
+use aoc_core::Part;
+use aoc_export::{Export, Frames, Rgb, Scene};
+
+use crate::{Day24, Floor, Tile, TilePath, DAYS};
+
+/// Pixels from the centre of a tile to its corners.
+const RADIUS: f64 = 6.0;
+
+const GROUT: Rgb = Rgb(110, 110, 110);
+const WHITE: Rgb = Rgb(240, 236, 225);
+const BLACK: Rgb = Rgb(30, 30, 30);
+
+impl Export for Day24 {
+    /// The floor once the tiles are flipped, then in part 2 after each day, all framed to fit the
+    /// most the black tiles spread to.
+    fn export(paths: &Vec<TilePath>, part: Part, frames: &mut Frames) -> io::Result<()> {
+        let mut floor = Floor::new();
+        for path in paths {
+            floor.flip(path.coords());
+        }
+
+        let days = match part {
+            Part::One => 0,
+            Part::Two => DAYS,
+        };
+        let mut floors = vec![floor.clone()];
+        for _ in 0..days {
+            floor.step();
+            floors.push(floor.clone());
+        }
+
+        // With a white tile's border around the black ones.
+        let tiles = floors.iter().flat_map(Floor::black_tiles);
+        let bounds = tiles.fold(((-2, -1), (2, 1)), |((min_x, min_y), (max_x, max_y)), (x, y)| {
+            ((min_x.min(x - 2), min_y.min(y - 1)), (max_x.max(x + 2), max_y.max(y + 1)))
+        });
+        for floor in &floors {
+            frames.push(draw(floor, bounds))?;
+        }
+        Ok(())
+    }
+}
+
+/// Draws every tile between the corners of `bounds`, as hexagons with their points up.
+fn draw(floor: &Floor, ((min_x, min_y), (max_x, max_y)): ((i32, i32), (i32, i32))) -> Scene {
+    // A tile is this wide, so each step east in doubled coordinates is half of it.
+    let across = 3f64.sqrt() * RADIUS;
+    let width = (max_x - min_x) as f64 * across / 2.0 + across;
+    let height = (max_y - min_y) as f64 * 1.5 * RADIUS + 2.0 * RADIUS;
+    let mut scene = Scene::new(width.ceil() as usize, height.ceil() as usize, GROUT);
+
+    for y in min_y..=max_y {
+        // Tiles only sit where `x + y` is even.
+        for x in (min_x..=max_x).filter(|x| (x + y) % 2 == 0) {
+            let centre = ((x - min_x) as f64 * across / 2.0 + across / 2.0, (max_y - y) as f64 * 1.5 * RADIUS + RADIUS);
+            let corners = (0..6)
+                .map(|i| PI / 6.0 + i as f64 * PI / 3.0)
+                .map(|angle| (centre.0 + (RADIUS - 0.5) * angle.cos(), centre.1 + (RADIUS - 0.5) * angle.sin()))
+                .collect();
+            scene.polygon(corners, if floor.get((x, y)) == Tile::Black { BLACK } else { WHITE });
+        }
+    }
+    scene
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_export::{raster, Format, Shape};
+
+    use super::*;
+    use crate::{process_input, test::INPUT};
+
+    #[test]
+    fn test_draw() {
+        let mut floor = Floor::new();
+        floor.flip((0, 0));
+        floor.flip((1, 1));
+        let scene = draw(&floor, ((-2, -1), (3, 2)));
+        let black = scene.shapes().iter().filter(|shape| matches!(shape, Shape::Polygon { colour: BLACK, .. })).count();
+        assert_eq!(2, black);
+        assert_eq!(12, scene.shapes().len());
+
+        // The middle of each black tile comes out black.
+        let image = raster::rasterize(&scene);
+        let across = 3f64.sqrt() * RADIUS;
+        assert_eq!(BLACK, image[((2.0 * across / 2.0 + across / 2.0) as usize, (2.0 * 1.5 * RADIUS + RADIUS) as usize)]);
+        assert_eq!(BLACK, image[((3.0 * across / 2.0 + across / 2.0) as usize, (1.5 * RADIUS + RADIUS) as usize)]);
+        assert_eq!(WHITE, image[((across / 2.0) as usize, RADIUS as usize)]);
+    }
+
+    #[test]
+    fn test_export() {
+        let paths = process_input(INPUT).unwrap();
+        let mut frames = Frames::file(std::env::temp_dir().join("day24-export-test.ppm"), Format::Ppm);
+        Day24::export(&paths, Part::Two, &mut frames).unwrap();
+        assert_eq!(DAYS + 1, frames.count());
+    }
+}
@@ -0,0 +1,104 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::generate::Generated;
+use rand::{seq::SliceRandom, Rng};
+
+use crate::Floor;
+
+/// Number of paths in a generated list.
+pub const DEFAULT_SIZE: usize = 400;
+
+const STEPS: [(&str, (i32, i32)); 6] = [
+    ("e", (2, 0)),
+    ("se", (1, -1)),
+    ("sw", (-1, -1)),
+    ("w", (-2, 0)),
+    ("nw", (-1, 1)),
+    ("ne", (1, 1)),
+];
+
+/// `size` paths to tiles within ten steps of the reference tile, some of them visited more than
+/// once.
+///
+/// Each path is the shortest way to its tile padded with pairs of opposite steps, then shuffled,
+/// so the tiles flipped are known without following the paths. Part 2 is worked out by running
+/// the 100 days over the set of black tiles.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+    let mut targets: Vec<(i32, i32)> = Vec::with_capacity(size);
+    while targets.len() < size {
+        if !targets.is_empty() && rng.gen_bool(0.15) {
+            let again = *targets.choose(rng).unwrap();
+            targets.push(again);
+        } else {
+            let y: i32 = rng.gen_range(-10..=10);
+            let x = 2 * rng.gen_range(-10..=10) + y.rem_euclid(2);
+            targets.push((x, y));
+        }
+    }
+
+    let mut black = HashSet::new();
+    let mut input = String::new();
+    for &target in &targets {
+        if !black.remove(&target) {
+            black.insert(target);
+        }
+        let mut path = shortest_path(target);
+        let detours = if path.is_empty() { 1 } else { 0 };
+        for _ in 0..rng.gen_range(detours..=6) {
+            let step = rng.gen_range(0..6);
+            path.extend([step, (step + 3) % 6].iter());
+        }
+        path.shuffle(rng);
+        input += &path.iter().map(|&step| STEPS[step].0).collect::<String>();
+        input.push('\n');
+    }
+    let part1 = black.len();
+
+    for _ in 0..100 {
+        let mut neighbours: HashMap<(i32, i32), usize> = HashMap::new();
+        for &(x, y) in &black {
+            for (dx, dy) in &Floor::ADJACENT {
+                *neighbours.entry((x + dx, y + dy)).or_default() += 1;
+            }
+        }
+        black = neighbours.into_iter()
+            .filter(|(tile, count)| *count == 2 || (*count == 1 && black.contains(tile)))
+            .map(|(tile, _)| tile)
+            .collect();
+    }
+
+    Generated::new(input, Some(part1.to_string()), Some(black.len().to_string()))
+}
+
+/// Indexes into [`STEPS`] that reach `(x, y)`: diagonal steps to the right row, then east or
+/// west along it.
+fn shortest_path((x, y): (i32, i32)) -> Vec<usize> {
+    let (east, west) = if y > 0 { (5, 4) } else { (1, 2) };
+    let rows = y.abs();
+    let leaning_east = ((x + rows) / 2).clamp(0, rows);
+    let mut path = vec![east; leaning_east as usize];
+    path.extend(vec![west; (rows - leaning_east) as usize]);
+
+    let along = x - (2 * leaning_east - rows);
+    path.extend(vec![if along > 0 { 0 } else { 3 }; (along.abs() / 2) as usize]);
+    path
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{Day24, TilePath};
+
+    #[test]
+    fn test_generate() {
+        for target in [(0, 0), (4, 0), (-3, 1), (1, -5), (12, -2), (-9, 9)].iter() {
+            let path: String = shortest_path(*target).iter().map(|&step| STEPS[step].0).collect();
+            assert_eq!(*target, path.parse::<TilePath>().unwrap().coords());
+        }
+        for seed in 0..2 {
+            generate(&mut StdRng::seed_from_u64(seed), 15).assert_solves::<Day24>();
+        }
+    }
+}
@@ -0,0 +1,303 @@
+use aoc_automaton::{Automaton, Hex, Rule};
+use aoc_core::{parse, ParseError, Solver};
+use aoc_nom::{context, tag, IResult};
+use nom::{
+    branch::alt,
+    combinator::{eof, map, value},
+    multi::many_till,
+};
+
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "export")]
+pub mod export;
+#[cfg(feature = "generate")]
+pub mod generate;
+#[cfg(feature = "repl")]
+pub mod repl;
+
+/// Day 24: Lobby Layout
+pub struct Day24;
+
+impl Solver for Day24 {
+    const DAY: u8 = 24;
+
+    type Input<'a> = Vec<TilePath>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        process_input(input)
+    }
+
+    fn part1(paths: &Self::Input<'_>) -> usize {
+        get_answer(paths)
+    }
+
+    fn part2(paths: &Self::Input<'_>) -> usize {
+        get_answer2(paths, DAYS)
+    }
+}
+
+fn process_input(input: &str) -> Result<Vec<TilePath>, ParseError> {
+    parse::lines(input, TilePath::from_str)
+}
+
+fn get_answer(paths: &[TilePath]) -> usize {
+    let mut floor = Floor::new();
+    for path in paths {
+        floor.flip(path.coords());
+    }
+    floor.count()
+}
+
+fn get_answer2(paths: &[TilePath], iterations: usize) -> usize {
+    let mut floor = Floor::new();
+    for path in paths {
+        floor.flip(path.coords());
+    }
+
+    for _ in 0..iterations {
+        floor.step();
+    }
+
+    floor.count()
+}
+
+/// How many days part 2 runs the floor for.
+pub const DAYS: usize = 100;
+
+/// Black tiles stay black with 1 or 2 black neighbours, and white tiles turn black with exactly 2.
+pub const RULE: Rule = Rule::new(&[2], &[1, 2]);
+
+/// The lobby floor, in doubled coordinates: east is `(2, 0)` and north-east is `(1, 1)`.
+#[derive(Clone, Debug)]
+pub struct Floor(Automaton<Hex>);
+
+impl Floor {
+    /// Offsets to the six neighbours of a tile.
+    pub const ADJACENT: [(i32, i32); 6] = Hex::ADJACENT;
+
+    pub fn new() -> Self {
+        Floor(Automaton::new(Hex, RULE, None))
+    }
+
+    pub fn get(&self, coords: (i32, i32)) -> Tile {
+        if self.0.is_live(coords) {
+            Tile::Black
+        } else {
+            Tile::White
+        }
+    }
+
+    pub fn flip(&mut self, coords: (i32, i32)) {
+        self.0.toggle(coords);
+    }
+
+    /// Runs one day, by [`RULE`].
+    pub fn step(&mut self) {
+        self.0.step();
+    }
+
+    pub fn black_tiles(&self) -> impl Iterator<Item = (i32, i32)> + '_ {
+        self.0.live().iter().copied()
+    }
+
+    /// The number of black tiles.
+    pub fn count(&self) -> usize {
+        self.0.count()
+    }
+}
+
+/// Draws the floor around its black tiles as `#`, with the white ones as `.` and gaps between
+/// tiles in the same row, north at the top.
+impl fmt::Display for Floor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let xs = self.0.live().iter().map(|&(x, _)| x);
+        let ys = self.0.live().iter().map(|&(_, y)| y);
+        let (min_x, max_x) = (xs.clone().min().unwrap_or(0), xs.max().unwrap_or(0));
+        let (min_y, max_y) = (ys.clone().min().unwrap_or(0), ys.max().unwrap_or(0));
+
+        for y in (min_y..=max_y).rev() {
+            let row: String = (min_x..=max_x)
+                .map(|x| match self.get((x, y)) {
+                    _ if (x + y) % 2 != 0 => ' ',
+                    Tile::Black => '#',
+                    Tile::White => '.',
+                })
+                .collect();
+            writeln!(f, "{}", row.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for Floor {
+    fn default() -> Self {
+        Floor::new()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Default)]
+pub enum Tile {
+    #[default]
+    White,
+    Black,
+}
+
+impl Tile {
+    pub fn toggle(&mut self) {
+        match self {
+            Tile::White => *self = Tile::Black,
+            Tile::Black => *self = Tile::White,
+        }
+    }
+}
+
+
+/// Steps from the reference tile to a tile, e.g. `esenee`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TilePath(pub Vec<Direction>);
+
+impl FromStr for TilePath {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let path = map(many_till(parse_direction, eof), |(directions, _)| TilePath(directions));
+        aoc_nom::finish(input, path)
+    }
+}
+
+impl TilePath {
+    /// Where the path ends up.
+    pub fn coords(&self) -> (i32, i32) {
+        let mut x = 0;
+        let mut y = 0;
+
+        for direction in &self.0 {
+            match direction {
+                Direction::East => {x += 2},
+                Direction::SouthEast => {x += 1; y -= 1},
+                Direction::SouthWest => {x -= 1; y -= 1},
+                Direction::West => {x -= 2},
+                Direction::NorthWest => {x -= 1; y += 1},
+                Direction::NorthEast => {x += 1; y += 1},
+            }
+        }
+
+        (x, y)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Direction {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+fn parse_direction(i: &str) -> IResult<'_, Direction> {
+    let east = value(Direction::East, tag("e"));
+    let southeast = value(Direction::SouthEast, tag("se"));
+    let southwest = value(Direction::SouthWest, tag("sw"));
+    let west = value(Direction::West, tag("w"));
+    let northwest = value(Direction::NorthWest, tag("nw"));
+    let northeast = value(Direction::NorthEast, tag("ne"));
+
+    context(
+        "`e`, `se`, `sw`, `w`, `nw` or `ne`",
+        alt((east, southeast, southwest, west, northwest, northeast)),
+    )(i)
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    fn direction() -> impl Strategy<Value=Direction> {
+        prop_oneof![
+            Just(Direction::East),
+            Just(Direction::SouthEast),
+            Just(Direction::SouthWest),
+            Just(Direction::West),
+            Just(Direction::NorthWest),
+            Just(Direction::NorthEast),
+        ]
+    }
+
+    /// A path of up to 40 steps, alongside the same steps in another order.
+    fn shuffled_path() -> impl Strategy<Value=(Vec<Direction>, Vec<Direction>)> {
+        vec(direction(), 0..40).prop_flat_map(|path| (Just(path.clone()), Just(path).prop_shuffle()))
+    }
+
+    fn render(path: &[Direction]) -> String {
+        path.iter().map(|direction| match direction {
+            Direction::East => "e",
+            Direction::SouthEast => "se",
+            Direction::SouthWest => "sw",
+            Direction::West => "w",
+            Direction::NorthWest => "nw",
+            Direction::NorthEast => "ne",
+        }).collect()
+    }
+
+    #[test]
+    fn test_answer() {
+        let paths = process_input(INPUT).unwrap();
+        assert_eq!(10, get_answer(&paths));
+    }
+
+    #[test]
+    fn test_answer2() {
+        let paths = process_input(INPUT).unwrap();
+        assert_eq!(2208, get_answer2(&paths, 100));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = process_input("esew\nnwwswee\nsenxe").err();
+        assert_eq!(Some(ParseError::new(3, "`e`, `se`, `sw`, `w`, `nw` or `ne`", "nxe").on_line(3)), error);
+    }
+
+    proptest! {
+        #[test]
+        fn coords_ignore_step_order((path, shuffled) in shuffled_path()) {
+            prop_assert_eq!(TilePath(path).coords(), TilePath(shuffled).coords());
+        }
+
+        #[test]
+        fn parse_reads_rendered_path(path in vec(direction(), 0..40)) {
+            prop_assert_eq!(Ok(TilePath(path.clone())), TilePath::from_str(&render(&path)));
+        }
+    }
+
+    pub(crate) const INPUT: &str = "\
+sesenwnenenewseeswwswswwnenewsewsw
+neeenesenwnwwswnenewnwwsewnenwseswesw
+seswneswswsenwwnwse
+nwnwneseeswswnenewneswwnewseswneseene
+swweswneswnenwsewnwneneseenw
+eesenwseswswnenwswnwnwsewwnwsene
+sewnenenenesenwsewnenwwwse
+wenwwweseeeweswwwnwwe
+wsweesenenewnwwnwsenewsenwwsesesenwne
+neeswseenwwswnwswswnw
+nenwswwsewswnenenewsenwsenwnesesenew
+enewnwewneswsewnwswenweswnenwsenwsw
+sweneswneswneneenwnewenewwneswswnese
+swwesenesewenwneswnwwneseswwne
+enesenwswwswneneswsenwnewswseenwsese
+wnwnesenesenenwwnenwsewesewsesesew
+nenewswnwewswnenesenwnesewesw
+eneswnwswnwsenenwnwnwwseeswneewsenese
+neswnwewnwnwseenwseesewsenwsweewe
+wseweeenwnesenwwwswnew";
+
+}
@@ -0,0 +1,107 @@
+use aoc_core::repl::{self, Command, Explore, Session};
+use aoc_core::ParseError;
+
+use crate::{Day24, Floor, TilePath};
+
+const COMMANDS: &[Command] = &[
+    Command { name: "step", args: "[n]", help: "Run n days, or 1, and show the floor" },
+    Command { name: "show", args: "", help: "Show the floor around the black tiles" },
+    Command { name: "count", args: "", help: "Count the black tiles" },
+    Command { name: "flip", args: "<path>", help: "Flip the tile at the end of a path such as `nwwswee`" },
+    Command { name: "tile", args: "<path>", help: "Show the colour of the tile at the end of a path" },
+    Command { name: "reset", args: "", help: "Start again with the tiles flipped by the input" },
+];
+
+/// The lobby floor some days in.
+struct Lobby {
+    paths: Vec<TilePath>,
+    floor: Floor,
+    days: usize,
+}
+
+impl Lobby {
+    fn reset(&mut self) {
+        self.floor = Floor::new();
+        for path in &self.paths {
+            self.floor.flip(path.coords());
+        }
+        self.days = 0;
+    }
+
+    fn show(&self) -> String {
+        format!("Day {}: {} black\n{}", self.days, self.floor.count(), self.floor)
+    }
+}
+
+fn path(args: &str) -> Result<TilePath, String> {
+    args.parse().map_err(|e: ParseError| e.render("<repl>", args))
+}
+
+impl Explore for Day24 {
+    fn explore<'a>(paths: Self::Input<'a>) -> Box<dyn Session + 'a> {
+        let mut lobby = Lobby { paths, floor: Floor::new(), days: 0 };
+        lobby.reset();
+        Box::new(lobby)
+    }
+}
+
+impl Session for Lobby {
+    fn commands(&self) -> &'static [Command] {
+        COMMANDS
+    }
+
+    fn run(&mut self, command: &str, args: &str) -> Result<String, String> {
+        match command {
+            "step" => {
+                for _ in 0..repl::count(args)? {
+                    self.floor.step();
+                    self.days += 1;
+                }
+                Ok(self.show())
+            }
+            "show" => Ok(self.show()),
+            "count" => Ok(self.floor.count().to_string()),
+            "flip" => {
+                let coords = path(args)?.coords();
+                self.floor.flip(coords);
+                Ok(format!("{:?} is now {:?}", coords, self.floor.get(coords)))
+            }
+            "tile" => {
+                let coords = path(args)?.coords();
+                Ok(format!("{:?} is {:?}", coords, self.floor.get(coords)))
+            }
+            "reset" => {
+                self.reset();
+                Ok(String::new())
+            }
+            _ => unreachable!("not one of the commands"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{process_input, test::INPUT};
+
+    #[test]
+    fn test_session() {
+        let mut lobby = Day24::explore(process_input(INPUT).unwrap());
+        assert_eq!(Ok("10".to_owned()), lobby.run("count", ""));
+        assert!(lobby.run("step", "10").unwrap().starts_with("Day 10: 37 black\n"));
+
+        lobby.run("reset", "").unwrap();
+        // The reference tile starts out black.
+        assert_eq!(Ok("(0, 0) is now White".to_owned()), lobby.run("flip", "nwwswee"));
+        assert_eq!(Ok("(0, 0) is White".to_owned()), lobby.run("tile", ""));
+        assert!(lobby.run("tile", "n").is_err());
+    }
+
+    #[test]
+    fn test_show() {
+        let mut lobby = Day24::explore(Vec::new());
+        lobby.run("flip", "e").unwrap();
+        lobby.run("flip", "nw").unwrap();
+        assert_eq!(Ok("Day 0: 2 black\n# .\n . #\n".to_owned()), lobby.run("show", ""));
+    }
+}
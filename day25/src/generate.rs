@@ -0,0 +1,37 @@
+use aoc_core::generate::Generated;
+use rand::Rng;
+
+use crate::{transform, MODULUS, SUBJECT};
+
+/// Unused, as every handshake is a pair of keys.
+pub const DEFAULT_SIZE: usize = 2;
+
+/// The public keys of a card and a door with random loop sizes.
+///
+/// The keys are made with [`transform`], so the answer comes without having to find a loop size
+/// from a key the way the solver does. Part 2 is always the same.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, _size: usize) -> Generated {
+    let card_loop_size = rng.gen_range(1..MODULUS - 1);
+    let door_loop_size = rng.gen_range(1..MODULUS - 1);
+    let card_key = transform(SUBJECT, card_loop_size);
+    let door_key = transform(SUBJECT, door_loop_size);
+
+    let input = format!("{}\n{}\n", card_key, door_key);
+    let encryption_key = transform(door_key, card_loop_size);
+    Generated::new(input, Some(encryption_key.to_string()), Some("Merry Christmas!".to_owned()))
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day25;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            generate(&mut StdRng::seed_from_u64(seed), DEFAULT_SIZE).assert_solves::<Day25>();
+        }
+    }
+}
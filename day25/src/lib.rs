@@ -0,0 +1,87 @@
+use aoc_core::{ParseError, Solver};
+use aoc_math::{discrete_log, mod_pow};
+use aoc_nom::unsigned;
+use nom::{character::complete::line_ending, sequence::separated_pair};
+
+#[cfg(feature = "generate")]
+pub mod generate;
+
+/// Day 25: Combo Breaker
+pub struct Day25;
+
+impl Solver for Day25 {
+    const DAY: u8 = 25;
+
+    type Input<'a> = (i64, i64);
+    type Answer1 = i64;
+    type Answer2 = &'static str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        process_input(input)
+    }
+
+    fn part1((card_key, door_key): &Self::Input<'_>) -> i64 {
+        get_answer(*card_key, *door_key)
+    }
+
+    /// There's no second puzzle on the last day, just the star for finishing the rest.
+    fn part2(_keys: &Self::Input<'_>) -> &'static str {
+        "Merry Christmas!"
+    }
+}
+
+/// What the handshake's transforms work modulo.
+pub const MODULUS: i64 = 20201227;
+
+/// The subject number both devices transform to get their public keys.
+pub const SUBJECT: i64 = 7;
+
+fn process_input(input: &str) -> Result<(i64, i64), ParseError> {
+    aoc_nom::finish(input, separated_pair(unsigned, line_ending, unsigned))
+}
+
+fn get_answer(card_key: i64, door_key: i64) -> i64 {
+    let card_loop_size = loop_size(card_key).expect("the card's key isn't a power of the subject");
+    transform(door_key, card_loop_size)
+}
+
+/// Transforms `subject` by looping `loop_size` times, each time multiplying by it modulo
+/// [`MODULUS`].
+pub fn transform(subject: i64, loop_size: i64) -> i64 {
+    mod_pow(subject as i128, loop_size as u128, MODULUS as i128) as i64
+}
+
+/// The smallest loop size that transforms [`SUBJECT`] into `public_key`, found by discrete log
+/// rather than by trying every loop size in turn.
+pub fn loop_size(public_key: i64) -> Option<i64> {
+    discrete_log(SUBJECT as i128, public_key as i128, MODULUS as i128).map(|e| e as i64)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "\
+5764801
+17807724";
+
+    #[test]
+    fn test_loop_size() {
+        assert_eq!(Some(8), loop_size(5764801));
+        assert_eq!(Some(11), loop_size(17807724));
+        assert_eq!(5764801, transform(SUBJECT, 8));
+    }
+
+    #[test]
+    fn test_answer() {
+        let (card_key, door_key) = process_input(INPUT).unwrap();
+        assert_eq!(14897079, get_answer(card_key, door_key));
+        assert_eq!(14897079, get_answer(door_key, card_key));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = process_input("5764801\nkey").err();
+        assert_eq!(Some(ParseError::new(1, "a number", "key").on_line(2)), error);
+    }
+}
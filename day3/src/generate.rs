@@ -0,0 +1,41 @@
+use aoc_core::generate::Generated;
+use rand::Rng;
+
+use crate::TOBAGGANS;
+
+/// Number of rows in a generated map.
+pub const DEFAULT_SIZE: usize = 323;
+
+/// A map `size` rows deep and 31 columns wide, about a fifth of it trees.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+    let width = 31;
+    let rows: Vec<Vec<bool>> = (0..size.max(1))
+        .map(|_| (0..width).map(|_| rng.gen_bool(0.2)).collect())
+        .collect();
+
+    let trees = |right: usize, down: usize| {
+        rows.iter().step_by(down).enumerate().filter(|(step, row)| row[step * right % width]).count()
+    };
+    let part1 = trees(3, 1);
+    let part2 = TOBAGGANS.iter().map(|&(right, down)| trees(right, down)).product::<usize>();
+
+    let input = rows.iter()
+        .map(|row| row.iter().map(|&tree| if tree { '#' } else { '.' }).chain(Some('\n')).collect::<String>())
+        .collect();
+    Generated::new(input, Some(part1.to_string()), Some(part2.to_string()))
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day3;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            generate(&mut StdRng::seed_from_u64(seed), 100).assert_solves::<Day3>();
+        }
+    }
+}
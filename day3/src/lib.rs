@@ -0,0 +1,112 @@
+use aoc_core::{ParseError, Solver};
+use aoc_grid::{Grid, Point};
+
+#[cfg(feature = "generate")]
+pub mod generate;
+pub mod search;
+pub mod terrain;
+
+/// The slopes checked in part 2, as `(right, down)`.
+pub const TOBAGGANS: [(usize, usize); 5] = [
+    (1, 1),
+    (3, 1),
+    (5, 1),
+    (7, 1),
+    (1, 2),
+];
+
+/// Day 3: Toboggan Trajectory
+pub struct Day3;
+
+impl Solver for Day3 {
+    const DAY: u8 = 3;
+
+    type Input<'a> = Grid<bool>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Grid::parse(input)
+    }
+
+    fn part1(forest: &Self::Input<'_>) -> usize {
+        get_answer(forest)
+    }
+
+    fn part2(forest: &Self::Input<'_>) -> usize {
+        get_answer2(forest)
+    }
+}
+
+fn get_answer(forest: &Grid<bool>) -> usize {
+    count_trees(3, 1, forest)
+}
+
+fn get_answer2(forest: &Grid<bool>) -> usize {
+    let mut product = 1;
+    for (right, down) in &TOBAGGANS {
+        let answer = count_trees(*right, *down, forest);
+        product *= answer;
+    }
+    product
+}
+
+/// Counts the trees hit going `right` and `down` each step; the forest repeats to the right.
+pub fn count_trees(right: usize, down: usize, forest: &Grid<bool>) -> usize {
+    path_iter(right, down, forest)
+        .filter(|&(x, y)| *forest.get_wrapping((x as isize, y as isize)))
+        .count()
+}
+
+/// Every square landed on going `right` and `down` each step from the top left, down to the
+/// bottom of the forest.
+///
+/// The `x` of each square carries on counting past the right edge of the forest, as though the
+/// forest really did repeat.
+pub fn path<T>(right: usize, down: usize, forest: &Grid<T>) -> Vec<Point> {
+    path_iter(right, down, forest).collect()
+}
+
+/// The squares of [`path`], one at a time, for when there's no need to keep them.
+pub fn path_iter<T>(right: usize, down: usize, forest: &Grid<T>) -> impl Iterator<Item = Point> {
+    (0..forest.height())
+        .step_by(down)
+        .enumerate()
+        .map(move |(step, y)| (step * right, y))
+}
+
+#[cfg(test)]
+mod test {
+    use super:: *;
+
+    pub(crate) const INPUT: &str = "\
+..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#";
+
+    #[test]
+    fn example() {
+        let forest = Grid::parse(INPUT).unwrap();
+        let answer = count_trees(3, 1, &forest);
+        assert_eq!(7, answer);
+    }
+
+    #[test]
+    fn example2() {
+        let forest = Grid::parse(INPUT).unwrap();
+        let mut product = 1;
+        for (right, down) in &TOBAGGANS {
+            let answer = count_trees(*right, *down, &forest);
+            product *= answer;
+        }
+        assert_eq!(336, product);
+    }
+}
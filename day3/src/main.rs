@@ -1,95 +1,96 @@
-use std::fs::File;
-use std::io::Read;
+use std::process;
 
-const TOBAGGANS: [(usize, usize); 5] = [
-    (1, 1),
-    (3, 1),
-    (5, 1),
-    (7, 1),
-    (1, 2),
-];
+use aoc_core::output::Format;
+use aoc_core::{DayArgs, Solver};
+use clap::Parser;
+use day3::search::{self, Bounds, Goal};
+use day3::terrain::{self, Legend};
+use day3::{path, Day3};
+use serde_json::json;
 
-fn main() {
-    let mut buf = String::new();
-    let mut file = File::open("input").unwrap();
-    file.read_to_string(&mut buf).unwrap();
-
-    let mut product = 1;
-    for (right, down) in &TOBAGGANS {
-        let forest = buf.lines().map(Row::from_bytes);
-        let answer = count_trees(*right, *down, forest);
-        product *= answer;
-    }
-    println!("{}", product);
+/// Solve day 3 of Advent of Code 2020, or rank the slopes down the forest
+#[derive(Parser)]
+struct Args {
+    #[command(flatten)]
+    day: DayArgs,
+    /// List slopes by how many trees they hit, rather than solving the puzzle: `min` for the
+    /// fewest first or `max` for the most
+    #[arg(long, value_name = "GOAL")]
+    search: Option<Goal>,
+    /// Furthest right a slope can go each step, by default the width of the forest less one
+    #[arg(long, requires = "search")]
+    max_right: Option<usize>,
+    /// Furthest down a slope can go each step, by default the height of the forest
+    #[arg(long, requires = "search")]
+    max_down: Option<usize>,
+    /// Only list this many of the best slopes
+    #[arg(long, requires = "search")]
+    top: Option<usize>,
+    /// Draw the path one slope takes through the forest, rather than solving the puzzle
+    #[arg(long, conflicts_with = "search")]
+    draw: bool,
+    /// How far right the slope to draw goes each step
+    #[arg(long, default_value_t = 3, requires = "draw")]
+    right: usize,
+    /// How far down the slope to draw goes each step
+    #[arg(long, default_value_t = 1, requires = "draw")]
+    down: usize,
+    /// What the characters of the map stand for, as each one's cost to land on with 0 for open
+    /// ground, e.g. `.=0,#=1,^=5`
+    #[arg(long, default_value = ".=0,#=1", requires = "draw")]
+    legend: Legend,
 }
 
-struct Row<'a> {
-    trees: &'a[u8],
-}
-
-impl<'a> Row<'a> {
-    fn from_bytes(bytes: &'a (impl AsRef<[u8]> +?Sized)) -> Self {
-        Row {
-            trees: bytes.as_ref()
-        }
-    }
+fn main() {
+    let args = Args::parse();
+    args.day.output.apply();
 
-    fn has_tree_at(&self, column: usize) -> bool {
-        let column = column % self.trees.len();
-        let square = self.trees[column]; //panics
-        match square {
-            b'#' => true,
-            b'.' => false,
-            _ => panic!(),
-        }
+    if args.draw {
+        return draw(&args);
     }
-}
+    let goal = match args.search {
+        Some(goal) => goal,
+        None => return args.day.solve::<Day3>(),
+    };
+    let (source, buf) = args.day.read::<Day3>();
+    let forest = Day3::parse(&buf).unwrap_or_else(|e| {
+        eprint!("{}", e.render(&source.to_string(), &buf));
+        process::exit(1);
+    });
 
-fn count_trees<'a>(right: usize, down: usize, forest: impl Iterator<Item = Row<'a>>) -> usize {
-    let mut count = 0;
-    let mut column = 0;
-    for row in forest.into_iter().step_by(down) {
-        if row.has_tree_at(column) {
-            count += 1;
+    let default = Bounds::of(&forest);
+    let bounds = Bounds {
+        max_right: args.max_right.unwrap_or(default.max_right),
+        max_down: args.max_down.unwrap_or(default.max_down),
+    };
+    let ranked = search::search(&forest, bounds, goal);
+    for ranked in ranked.iter().take(args.top.unwrap_or(usize::MAX)) {
+        let (right, down) = ranked.slope;
+        match args.day.output.format {
+            Format::Text => println!("right {}, down {}: {} trees", right, down, ranked.trees),
+            Format::Json => println!("{}", json!({ "right": right, "down": down, "trees": ranked.trees })),
         }
-        column += right;
     }
-    count
 }
 
-#[cfg(test)]
-mod test {
-    use super:: *;
-
-const INPUT: &'static str = "\
-..##.......
-#...#...#..
-.#....#..#.
-..#.#...#.#
-.#...##..#.
-..#.##.....
-.#.#.#....#
-.#........#
-#.##...#...
-#...##....#
-.#..#...#.#";
-
-    #[test]
-    fn example() {
-        let forest = INPUT.lines().map(Row::from_bytes);
-        let answer = count_trees(3, 1, forest);
-        assert_eq!(7, answer);
+/// Prints the forest with the slope's path marked on it, and what the slope hits on stderr.
+fn draw(args: &Args) {
+    if args.down == 0 {
+        eprintln!("--down must be at least 1");
+        process::exit(2);
     }
+    let (source, buf) = args.day.read::<Day3>();
+    let map = args.legend.parse_map(&buf).unwrap_or_else(|e| {
+        eprint!("{}", e.render(&source.to_string(), &buf));
+        process::exit(1);
+    });
 
-    #[test]
-    fn example2() {
+    let path = path(args.right, args.down, &map);
+    print!("{}", terrain::render(&map, &args.legend, &path));
 
-        let mut product = 1;
-        for (right, down) in &TOBAGGANS {
-            let forest = INPUT.lines().map(Row::from_bytes);
-            let answer = count_trees(*right, *down, forest);
-            product *= answer;
-        }
-        assert_eq!(336, product);
-    }
+    let hits: Vec<_> = terrain::hits(&map, &args.legend, &path).into_iter()
+        .map(|(symbol, count)| format!("{} `{}`", count, symbol))
+        .collect();
+    let hits = if hits.is_empty() { "nothing".to_owned() } else { hits.join(", ") };
+    eprintln!("right {}, down {}: hit {}, costing {}", args.right, args.down, hits, terrain::cost(&map, &args.legend, &path));
 }
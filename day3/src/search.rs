@@ -0,0 +1,110 @@
+//! Looking for the slopes down the forest that hit the fewest or the most trees.
+
+use std::str::FromStr;
+
+use aoc_grid::Grid;
+
+use crate::count_trees;
+
+/// Whether the search wants the fewest trees or the most.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Goal {
+    Fewest,
+    Most,
+}
+
+impl FromStr for Goal {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "min" | "fewest" => Ok(Goal::Fewest),
+            "max" | "most" => Ok(Goal::Most),
+            _ => Err(format!("goal must be `min` or `max`, not `{}`", s)),
+        }
+    }
+}
+
+/// The slopes to try: every `right` from 0 up to `max_right` with every `down` from 1 up to
+/// `max_down`.
+///
+/// Moving `right` by the width of the forest or more lands on the same trees as moving by what's
+/// left over, so there's nothing to gain from a `max_right` past the width, other than seeing
+/// those slopes listed. A `down` of more than 1 gives the slopes between the whole numbers, such
+/// as 3 right for every 2 down.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Bounds {
+    pub max_right: usize,
+    pub max_down: usize,
+}
+
+impl Bounds {
+    /// Every slope that takes a different path through `forest`, with `down` as far as the
+    /// forest goes.
+    pub fn of(forest: &Grid<bool>) -> Self {
+        Bounds { max_right: forest.width().saturating_sub(1), max_down: forest.height().max(1) }
+    }
+}
+
+/// A slope, as `(right, down)`, and how many trees it hits.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Ranked {
+    pub slope: (usize, usize),
+    pub trees: usize,
+}
+
+/// Every slope within `bounds`, best first for `goal`.
+///
+/// Slopes that hit as many trees as each other are listed by their step down, then their step
+/// right, smallest first.
+pub fn search(forest: &Grid<bool>, bounds: Bounds, goal: Goal) -> Vec<Ranked> {
+    let mut ranked: Vec<_> = (1..=bounds.max_down)
+        .flat_map(|down| (0..=bounds.max_right).map(move |right| (right, down)))
+        .map(|(right, down)| Ranked { slope: (right, down), trees: count_trees(right, down, forest) })
+        .collect();
+
+    ranked.sort_by_key(|ranked| {
+        let (right, down) = ranked.slope;
+        let trees = match goal {
+            Goal::Fewest => ranked.trees as isize,
+            Goal::Most => -(ranked.trees as isize),
+        };
+        (trees, down, right)
+    });
+    ranked
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::INPUT;
+
+    #[test]
+    fn test_search() {
+        let forest = Grid::parse(INPUT).unwrap();
+        let bounds = Bounds { max_right: 7, max_down: 2 };
+
+        let most = search(&forest, bounds, Goal::Most);
+        assert_eq!(16, most.len());
+        assert_eq!(Ranked { slope: (7, 1), trees: 4 }, most.iter().find(|r| r.slope == (7, 1)).copied().unwrap());
+        assert!(most.windows(2).all(|pair| pair[0].trees >= pair[1].trees));
+        assert_eq!(count_trees(most[0].slope.0, most[0].slope.1, &forest), most[0].trees);
+
+        let fewest = search(&forest, bounds, Goal::Fewest);
+        assert!(fewest.windows(2).all(|pair| pair[0].trees <= pair[1].trees));
+        assert_eq!(most.last().unwrap().trees, fewest[0].trees);
+    }
+
+    #[test]
+    fn test_bounds() {
+        let forest = Grid::parse(INPUT).unwrap();
+        assert_eq!(Bounds { max_right: 10, max_down: 11 }, Bounds::of(&forest));
+        assert_eq!(11 * 11, search(&forest, Bounds::of(&forest), Goal::Fewest).len());
+    }
+
+    #[test]
+    fn test_goal() {
+        assert_eq!(Ok(Goal::Most), "max".parse());
+        assert!("middling".parse::<Goal>().is_err());
+    }
+}
@@ -0,0 +1,136 @@
+//! Maps with more in them than trees and open ground, where each kind of obstacle costs its own
+//! amount to land on, and pictures of the path a slope takes through them.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use aoc_core::ParseError;
+use aoc_grid::{Grid, Point};
+
+/// What each character of a map stands for, as how much it costs to land on, where open ground
+/// costs nothing.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Legend(BTreeMap<char, u32>);
+
+/// The puzzle's legend, with open ground as `.` and trees as `#` costing 1 each.
+impl Default for Legend {
+    fn default() -> Self {
+        Legend([('.', 0), ('#', 1)].iter().copied().collect())
+    }
+}
+
+/// Reads a comma-separated list of characters and their costs, e.g. `.=0,#=1,^=5`.
+impl FromStr for Legend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .map(|entry| {
+                let mut chars = entry.trim().chars();
+                let (symbol, rest) = (chars.next(), chars.as_str());
+                match (symbol, rest.strip_prefix('=').map(str::parse)) {
+                    (Some(symbol), Some(Ok(cost))) => Ok((symbol, cost)),
+                    _ => Err(format!("expected a character and its cost such as `#=1`, not `{}`", entry)),
+                }
+            })
+            .collect::<Result<_, _>>()
+            .map(Legend)
+    }
+}
+
+impl Legend {
+    /// What landing on `symbol` costs, if it's in the legend.
+    pub fn cost(&self, symbol: char) -> Option<u32> {
+        self.0.get(&symbol).copied()
+    }
+
+    /// Whether `symbol` is something to avoid, rather than open ground.
+    pub fn is_obstacle(&self, symbol: char) -> bool {
+        self.cost(symbol).is_some_and(|cost| cost > 0)
+    }
+
+    /// Parses a map, which can only use the characters in the legend.
+    pub fn parse_map(&self, input: &str) -> Result<Grid<char>, ParseError> {
+        let symbols: Vec<_> = self.0.keys().map(|symbol| format!("`{}`", symbol)).collect();
+        let expected = match symbols.split_last() {
+            Some((last, rest)) if !rest.is_empty() => format!("{} or {}", rest.join(", "), last),
+            _ => symbols.concat(),
+        };
+        Grid::parse_with(input, &expected, |symbol| self.0.contains_key(&symbol).then_some(symbol))
+    }
+}
+
+/// The obstacles landed on along `path`, as how many times each kind was hit.
+pub fn hits(map: &Grid<char>, legend: &Legend, path: &[Point]) -> BTreeMap<char, usize> {
+    let mut hits = BTreeMap::new();
+    for &point in path {
+        let symbol = at(map, point);
+        if legend.is_obstacle(symbol) {
+            *hits.entry(symbol).or_default() += 1;
+        }
+    }
+    hits
+}
+
+/// What landing on everything along `path` costs in all.
+pub fn cost(map: &Grid<char>, legend: &Legend, path: &[Point]) -> u64 {
+    path.iter().map(|&point| u64::from(legend.cost(at(map, point)).unwrap_or(0))).sum()
+}
+
+/// Draws the map repeated to the right as far as `path` goes, with each square it lands on
+/// marked as `X` if it's an obstacle and `O` if it's open, as in the puzzle.
+pub fn render(map: &Grid<char>, legend: &Legend, path: &[Point]) -> String {
+    let furthest = path.iter().map(|&(x, _)| x).max().unwrap_or(0);
+    let width = map.width().max(1) * (furthest / map.width().max(1) + 1);
+
+    let mut picture = Grid::from_fn(width, map.height(), |point| at(map, point));
+    for &point in path {
+        if let Some(square) = picture.get_mut(point) {
+            *square = if legend.is_obstacle(*square) { 'X' } else { 'O' };
+        }
+    }
+    picture.render(|&square| square)
+}
+
+/// The square at `(x, y)`, with `x` carrying on past the right edge of the map as it repeats.
+fn at(map: &Grid<char>, (x, y): Point) -> char {
+    map[(x % map.width(), y)]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{path, test::INPUT};
+
+    #[test]
+    fn test_render() {
+        let legend = Legend::default();
+        let map = legend.parse_map(INPUT).unwrap();
+        let path = path(3, 1, &map);
+        assert_eq!(7, hits(&map, &legend, &path)[&'#']);
+
+        let picture = render(&map, &legend, &path);
+        let rows: Vec<_> = picture.lines().collect();
+        assert!(rows.iter().all(|row| row.len() == 33));
+        assert_eq!("O.##.......", &rows[0][..11]);
+        assert_eq!("#..O#...#..", &rows[1][..11]);
+        assert_eq!(".#....X..#.", &rows[2][..11]);
+        assert_eq!(".#..#...#.#.#..#...#.#.#..#...X.#", rows[10]);
+        assert_eq!((7, 4), (picture.matches('X').count(), picture.matches('O').count()));
+    }
+
+    #[test]
+    fn test_legend() {
+        let legend: Legend = ".=0, #=1, ^=5".parse().unwrap();
+        let map = legend.parse_map(".^#\n#.^\n^^.").unwrap();
+        assert_eq!(0, cost(&map, &legend, &path(1, 1, &map)));
+        let across = path(2, 1, &map);
+        assert_eq!(10, cost(&map, &legend, &across));
+        assert_eq!(vec![('^', 2)], hits(&map, &legend, &across).into_iter().collect::<Vec<_>>());
+
+        let error = legend.parse_map(".^#\n#x^").unwrap_err();
+        assert_eq!(ParseError::new(2, "`#`, `.` or `^`", "x").on_line(2), error);
+        assert!("#".parse::<Legend>().is_err());
+        assert!("#=heavy".parse::<Legend>().is_err());
+    }
+}
@@ -0,0 +1,120 @@
+use aoc_core::generate::Generated;
+use rand::{seq::SliceRandom, Rng};
+
+/// Number of passports in a generated batch.
+pub const DEFAULT_SIZE: usize = 300;
+
+/// A valid value for each required field, then one that breaks the field's rule.
+type Values<R> = fn(&mut R) -> (String, String);
+
+/// A batch of `size` passports, each missing a required field now and then and with the odd
+/// value that breaks its field's rule.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+    let required: [(&str, Values<R>); 7] = [
+        ("byr", |rng| year(rng, 1920, 2002)),
+        ("iyr", |rng| year(rng, 2010, 2020)),
+        ("eyr", |rng| year(rng, 2020, 2030)),
+        ("hgt", height),
+        ("hcl", hair_color),
+        ("ecl", eye_color),
+        ("pid", passport_id),
+    ];
+
+    let (mut complete, mut valid) = (0, 0);
+    let mut passports = Vec::with_capacity(size);
+    for _ in 0..size {
+        let mut fields = Vec::new();
+        let mut all_valid = true;
+        for (name, values) in &required {
+            if rng.gen_bool(0.9) {
+                let (good, bad) = values(rng);
+                let is_valid = rng.gen_bool(0.93);
+                all_valid &= is_valid;
+                fields.push(format!("{}:{}", name, if is_valid { good } else { bad }));
+            }
+        }
+        if fields.len() == required.len() {
+            complete += 1;
+            valid += all_valid as usize;
+        }
+        if rng.gen_bool(0.5) {
+            fields.push(format!("cid:{}", rng.gen_range(1..350)));
+        }
+
+        fields.shuffle(rng);
+        let mut passport = String::new();
+        for (i, field) in fields.iter().enumerate() {
+            if i > 0 {
+                passport.push(if rng.gen_bool(0.25) { '\n' } else { ' ' });
+            }
+            passport += field;
+        }
+        passports.push(passport);
+    }
+
+    let input = passports.join("\n\n") + "\n";
+    Generated::new(input, Some(complete.to_string()), Some(valid.to_string()))
+}
+
+fn year<R: Rng + ?Sized>(rng: &mut R, from: u32, to: u32) -> (String, String) {
+    let bad = if rng.gen_bool(0.5) { rng.gen_range(1900..from) } else { rng.gen_range(to + 1..2040) };
+    (rng.gen_range(from..=to).to_string(), bad.to_string())
+}
+
+fn height<R: Rng + ?Sized>(rng: &mut R) -> (String, String) {
+    let good = if rng.gen_bool(0.5) {
+        format!("{}cm", rng.gen_range(150..=193))
+    } else {
+        format!("{}in", rng.gen_range(59..=76))
+    };
+    let bad = match rng.gen_range(0..3) {
+        0 => format!("{}cm", rng.gen_range(100..150)),
+        1 => format!("{}in", rng.gen_range(77..200)),
+        _ => rng.gen_range(59..=193).to_string(),
+    };
+    (good, bad)
+}
+
+fn hex<R: Rng + ?Sized>(rng: &mut R, len: usize) -> String {
+    (0..len).map(|_| *b"0123456789abcdef".choose(rng).unwrap() as char).collect()
+}
+
+fn hair_color<R: Rng + ?Sized>(rng: &mut R) -> (String, String) {
+    let bad = match rng.gen_range(0..3) {
+        0 => hex(rng, 6),
+        1 => format!("#{}", hex(rng, 5)),
+        _ => format!("#{}z", hex(rng, 5)),
+    };
+    (format!("#{}", hex(rng, 6)), bad)
+}
+
+fn eye_color<R: Rng + ?Sized>(rng: &mut R) -> (String, String) {
+    let good = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"].choose(rng).unwrap();
+    let bad = ["zzz", "xry", "gmt", "dne", "lzr"].choose(rng).unwrap();
+    (good.to_string(), bad.to_string())
+}
+
+fn passport_id<R: Rng + ?Sized>(rng: &mut R) -> (String, String) {
+    let digits = |rng: &mut R, len| -> String { (0..len).map(|_| rng.gen_range(0..10).to_string()).collect() };
+    let bad = match rng.gen_range(0..3) {
+        0 => digits(rng, 8),
+        1 => digits(rng, 10),
+        _ => format!("{}a", digits(rng, 8)),
+    };
+    (digits(rng, 9), bad)
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day4;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            generate(&mut StdRng::seed_from_u64(seed), 50).assert_solves::<Day4>();
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use aoc_core::{ParseError, Solver};
+use aoc_nom::{blocks, context, field, record, IResult};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    combinator::{map, value},
+};
+
+use schema::{Document, Schema};
+
+#[cfg(feature = "generate")]
+pub mod generate;
+pub mod report;
+pub mod schema;
+
+/// Day 4: Passport Processing
+pub struct Day4;
+
+impl Solver for Day4 {
+    const DAY: u8 = 4;
+
+    type Input<'a> = Vec<PassportRaw>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        process_input(input)
+    }
+
+    fn part1(passports: &Self::Input<'_>) -> usize {
+        get_answer(passports)
+    }
+
+    fn part2(passports: &Self::Input<'_>) -> usize {
+        get_answer2(passports)
+    }
+}
+
+fn process_input(input: &str) -> Result<Vec<PassportRaw>, ParseError> {
+    aoc_nom::finish(input, blocks(map(record(parse_field_name, field), PassportRaw::new)))
+}
+
+fn get_answer(passports: &[PassportRaw]) -> usize {
+    let schema = Schema::passport();
+    passports.iter()
+        .filter(|p| schema.has_required(&p.document()))
+        .count()
+}
+
+fn get_answer2(passports: &[PassportRaw]) -> usize {
+    let schema = Schema::passport();
+    passports.iter()
+        .filter(|p| schema.validate(&p.document()))
+        .count()
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum Field {
+    BirthYear,
+    IssueYear,
+    ExpirationYear,
+    Height,
+    HairColor,
+    EyeColor,
+    PassportId,
+    CountryId,
+}
+
+impl Field {
+    /// The field's name as it's written in passports and the passport [`Schema`].
+    fn name(self) -> &'static str {
+        match self {
+            Field::BirthYear => "byr",
+            Field::IssueYear => "iyr",
+            Field::ExpirationYear => "eyr",
+            Field::Height => "hgt",
+            Field::HairColor => "hcl",
+            Field::EyeColor => "ecl",
+            Field::PassportId => "pid",
+            Field::CountryId => "cid",
+        }
+    }
+}
+
+/// A passport's fields, in the order they were written, before any of them have been checked.
+#[derive(Clone, Debug)]
+pub struct PassportRaw (Vec<(Field, String)>);
+
+impl PassportRaw {
+    fn new(fields: Vec<(Field, &str)>) -> Self {
+        PassportRaw(fields.into_iter().map(|(name, value)| (name, value.to_string())).collect())
+    }
+
+    /// The passport as a [`Document`] to check against a [`Schema`].
+    pub fn document(&self) -> Document<'_> {
+        self.0.iter().map(|(field, value)| (field.name(), value.as_str())).collect()
+    }
+}
+
+fn parse_field_name(i: &str) -> IResult<'_, Field> {
+    let byr = value(Field::BirthYear, tag("byr"));
+    let iyr = value(Field::IssueYear, tag("iyr"));
+    let eyr = value(Field::ExpirationYear, tag("eyr"));
+    let hgt = value(Field::Height, tag("hgt"));
+    let hcl = value(Field::HairColor, tag("hcl"));
+    let ecl = value(Field::EyeColor, tag("ecl"));
+    let pid = value(Field::PassportId, tag("pid"));
+    let cid = value(Field::CountryId, tag("cid"));
+
+    context(
+        "one of `byr`, `iyr`, `eyr`, `hgt`, `hcl`, `ecl`, `pid` or `cid`",
+        alt((byr,iyr,eyr,hgt,hcl,ecl,pid,cid)),
+    )(i)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn part1() {
+        let input = "\
+ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+byr:1937 iyr:2017 cid:147 hgt:183cm
+
+iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+hcl:#cfa07d byr:1929
+
+hcl:#ae17e1 iyr:2013
+eyr:2024
+ecl:brn pid:760753108 byr:1931
+hgt:179cm
+
+hcl:#cfa07d eyr:2025 pid:166559648
+iyr:2011 ecl:brn hgt:59in";
+
+        let answer = get_answer(&process_input(input).unwrap());
+        assert_eq!(2, answer);
+    }
+
+    #[test]
+    fn part2() {
+        let invalid = "\
+eyr:1972 cid:100
+hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
+
+iyr:2019
+hcl:#602927 eyr:1967 hgt:170cm
+ecl:grn pid:012533040 byr:1946
+
+hcl:dab227 iyr:2012
+ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
+
+hgt:59cm ecl:zzz
+eyr:2038 hcl:74454a iyr:2023
+pid:3556412378 byr:2007";
+
+        let valid = "\
+pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
+hcl:#623a2f
+
+eyr:2029 ecl:blu cid:129 byr:1989
+iyr:2014 pid:896056539 hcl:#a97842 hgt:165cm
+
+hcl:#888785
+hgt:164cm byr:2001 iyr:2015 cid:88
+pid:545766238 ecl:hzl
+eyr:2022
+
+iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719";
+
+        assert_eq!(0, get_answer2(&process_input(invalid).unwrap()));
+        assert_eq!(4, get_answer2(&process_input(valid).unwrap()));
+    }
+
+    #[test]
+    fn parse_error() {
+        let error = process_input("ecl:gry\nbyr:1937 pix:860033327").err().unwrap();
+        assert_eq!((2, 10, "pix"), (error.line, error.column, &*error.found));
+    }
+}
\ No newline at end of file
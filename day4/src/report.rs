@@ -0,0 +1,229 @@
+//! Explaining why documents aren't valid by a [`Schema`], one problem at a time, along with the
+//! fields it doesn't expect or expects only once, and totting up the problems across a whole
+//! batch of them.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::schema::{Document, Schema};
+
+/// One thing wrong with a document.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Problem {
+    /// A required field isn't there.
+    Missing { field: String },
+    /// A field's value isn't of the schema's [`Kind`](crate::schema::Kind), for `reason`.
+    Invalid { field: String, value: String, reason: String },
+    /// A field the schema doesn't have, which [`Schema::validate`] leaves alone.
+    Unknown { field: String },
+    /// A field written more than once, `count` times in all, which [`Schema::validate`] allows
+    /// as long as every value is of the right kind.
+    Duplicate { field: String, count: usize },
+}
+
+impl Problem {
+    /// The field the problem is with.
+    pub fn field(&self) -> &str {
+        match self {
+            Problem::Missing { field }
+            | Problem::Invalid { field, .. }
+            | Problem::Unknown { field }
+            | Problem::Duplicate { field, .. } => field,
+        }
+    }
+
+    /// Whether the problem makes the document invalid, rather than only being worth a mention.
+    pub fn is_invalid(&self) -> bool {
+        matches!(self, Problem::Missing { .. } | Problem::Invalid { .. })
+    }
+}
+
+/// Whether a document with `problems` is valid, which agrees with [`Schema::validate`].
+pub fn is_valid(problems: &[Problem]) -> bool {
+    !problems.iter().any(Problem::is_invalid)
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Problem::Missing { field } => write!(f, "{} is missing", field),
+            Problem::Invalid { field, value, reason } => write!(f, "{} {} {}", field, value, reason),
+            Problem::Unknown { field } => write!(f, "{} is not in the schema", field),
+            Problem::Duplicate { field, count } => write!(f, "{} appears {} times", field, count),
+        }
+    }
+}
+
+/// Everything wrong with `document` by `schema`: its missing fields in the order of the schema,
+/// then the problems with its own fields in the order they were written.
+///
+/// A field that's written more than once is reported as a duplicate the second time, and each of
+/// its values is checked.
+pub fn problems(schema: &Schema, document: &[(&str, &str)]) -> Vec<Problem> {
+    let mut problems: Vec<_> = schema.fields.iter()
+        .filter(|rule| rule.required && document.iter().all(|(name, _)| *name != rule.name))
+        .map(|rule| Problem::Missing { field: rule.name.clone() })
+        .collect();
+
+    for (index, &(name, value)) in document.iter().enumerate() {
+        let rule = match schema.fields.iter().find(|rule| rule.name == name) {
+            Some(rule) => rule,
+            None => {
+                problems.push(Problem::Unknown { field: name.to_owned() });
+                continue;
+            }
+        };
+        let earlier = document[..index].iter().filter(|(other, _)| *other == name).count();
+        if earlier == 1 {
+            let count = document.iter().filter(|(other, _)| *other == name).count();
+            problems.push(Problem::Duplicate { field: name.to_owned(), count });
+        }
+        if let Some(reason) = rule.kind.explain(value) {
+            problems.push(Problem::Invalid { field: name.to_owned(), value: value.to_owned(), reason });
+        }
+    }
+    problems
+}
+
+/// How many problems of each sort a field had across a batch of documents.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Counts {
+    pub missing: usize,
+    pub invalid: usize,
+    pub unknown: usize,
+    pub duplicate: usize,
+}
+
+impl fmt::Display for Counts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let counts = [
+            (self.missing, "missing"),
+            (self.invalid, "invalid"),
+            (self.unknown, "unknown"),
+            (self.duplicate, "duplicate"),
+        ];
+        let counts: Vec<_> = counts.iter()
+            .filter(|(count, _)| *count > 0)
+            .map(|(count, sort)| format!("{} {}", count, sort))
+            .collect();
+        write!(f, "{}", counts.join(", "))
+    }
+}
+
+/// How many documents of a batch were valid, and which fields they had problems with.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Summary {
+    pub valid: usize,
+    pub invalid: usize,
+    pub fields: BTreeMap<String, Counts>,
+}
+
+impl Summary {
+    fn add(&mut self, problems: &[Problem]) {
+        if is_valid(problems) {
+            self.valid += 1;
+        } else {
+            self.invalid += 1;
+        }
+        for problem in problems {
+            let counts = self.fields.entry(problem.field().to_owned()).or_default();
+            match problem {
+                Problem::Missing { .. } => counts.missing += 1,
+                Problem::Invalid { .. } => counts.invalid += 1,
+                Problem::Unknown { .. } => counts.unknown += 1,
+                Problem::Duplicate { .. } => counts.duplicate += 1,
+            }
+        }
+    }
+}
+
+/// The counts of valid and invalid documents, then a line for each field that had problems.
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} valid, {} invalid", self.valid, self.invalid)?;
+        for (field, counts) in &self.fields {
+            write!(f, "\n{}: {}", field, counts)?;
+        }
+        Ok(())
+    }
+}
+
+/// The problems with each of `documents` by `schema`, and a summary of them all.
+pub fn check(schema: &Schema, documents: &[Document<'_>]) -> (Vec<Vec<Problem>>, Summary) {
+    let mut summary = Summary::default();
+    let reports = documents.iter()
+        .map(|document| {
+            let problems = problems(schema, document);
+            summary.add(&problems);
+            problems
+        })
+        .collect();
+    (reports, summary)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::schema::parse_documents;
+
+    #[test]
+    fn test_problems() {
+        let schema = Schema::passport();
+        let documents = parse_documents("\
+pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980 hcl:#623a2f
+
+hgt:190in ecl:zzz iyr:2012 eyr:2030 byr:1980 hcl:#623a2f hcl:#623a2f nick:ada").unwrap();
+
+        assert!(problems(&schema, &documents[0]).is_empty());
+        let problems: Vec<_> = problems(&schema, &documents[1]).iter().map(ToString::to_string).collect();
+        assert_eq!(vec![
+            "pid is missing",
+            "hgt 190in exceeds 76in",
+            "ecl zzz is not one of amb, blu, brn, gry, grn, hzl, oth",
+            "hcl appears 2 times",
+            "nick is not in the schema",
+        ], problems);
+    }
+
+    #[test]
+    fn test_explain() {
+        let schema = Schema::passport();
+        let explain = |field: usize, value: &str| schema.fields[field].kind.explain(value);
+        assert_eq!(Some("is before 1920".to_owned()), explain(0, "1919"));
+        assert_eq!(Some("is not a four-digit year".to_owned()), explain(0, "02002"));
+        assert_eq!(Some("is under 150cm".to_owned()), explain(3, "149cm"));
+        assert_eq!(Some("has no unit out of cm, in".to_owned()), explain(3, "170"));
+        assert_eq!(Some("is not a number of in".to_owned()), explain(3, "-60in"));
+        assert_eq!(Some("is not 9 digits".to_owned()), explain(6, "0123456789"));
+        assert_eq!(None, explain(7, "anything"));
+    }
+
+    #[test]
+    fn test_check() {
+        let documents = parse_documents("byr:1920\n\nbyr:1919 byr:2003\n\ncid:1").unwrap();
+        let (reports, summary) = check(&Schema::passport(), &documents);
+        assert_eq!(3, reports.len());
+        assert_eq!((0, 3), (summary.valid, summary.invalid));
+        assert_eq!(Counts { missing: 1, invalid: 2, unknown: 0, duplicate: 1 }, summary.fields["byr"]);
+        assert_eq!(Counts { missing: 3, ..Counts::default() }, summary.fields["pid"]);
+        assert!(!summary.fields.contains_key("cid"));
+        assert!(summary.to_string().starts_with("0 valid, 3 invalid\nbyr: 1 missing, 2 invalid, 1 duplicate\n"));
+    }
+
+    #[test]
+    fn test_is_valid() {
+        let schema = Schema::passport();
+        let documents = parse_documents("\
+pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980 hcl:#623a2f byr:1981 nick:ada
+
+pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980 hcl:#623a2f byr:1919").unwrap();
+
+        let (reports, summary) = check(&schema, &documents);
+        assert_eq!(2, reports[0].len());
+        assert!(is_valid(&reports[0]));
+        assert!(!is_valid(&reports[1]));
+        assert_eq!((1, 1), (summary.valid, summary.invalid));
+        let valid: Vec<_> = documents.iter().map(|document| schema.validate(document)).collect();
+        assert_eq!(vec![true, false], valid);
+    }
+}
@@ -0,0 +1,283 @@
+//! Describing documents like passports in a file, rather than in code, so that other kinds can be
+//! checked without recompiling.
+//!
+//! A schema has a line for each field: its name, whether it's `required` or `optional`, and the
+//! [`Kind`] of value it takes. Blank lines and lines starting with `#` are ignored.
+//!
+//! ```text
+//! byr required year 1920-2002
+//! hgt required measure cm 150-193 in 59-76
+//! ecl required one-of amb blu brn gry grn hzl oth
+//! cid optional any
+//! ```
+
+use std::ops::RangeInclusive;
+
+use aoc_core::ParseError;
+use aoc_nom::{blocks, context, field, record, tag, unsigned, IResult};
+use nom::{
+    branch::alt,
+    bytes::complete::take_while1,
+    character::complete::{char, space1},
+    combinator::{map, value},
+    multi::separated_list1,
+    sequence::{preceded, separated_pair, tuple},
+};
+
+/// A document's fields as `(name, value)` pairs, in the order they were written.
+pub type Document<'a> = Vec<(&'a str, &'a str)>;
+
+/// The puzzle's passport schema.
+const PASSPORT: &str = include_str!("../passport.schema");
+
+/// The fields a kind of document has, and what each one's value has to be.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Schema {
+    pub fields: Vec<FieldRule>,
+}
+
+/// One line of a schema.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FieldRule {
+    pub name: String,
+    pub required: bool,
+    pub kind: Kind,
+}
+
+/// What a field's value has to be.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Kind {
+    /// `year MIN-MAX`: four digits, from `MIN` to `MAX`.
+    Year(RangeInclusive<u32>),
+    /// `measure UNIT MIN-MAX...`: a number followed by one of the units, in that unit's range.
+    Measure(Vec<(String, RangeInclusive<u32>)>),
+    /// `colour`: `#` followed by six hex digits.
+    Colour,
+    /// `one-of WORD...`: exactly one of the words.
+    OneOf(Vec<String>),
+    /// `digits N`: exactly `N` digits, leading zeroes and all.
+    Digits(usize),
+    /// `any`: anything at all.
+    Any,
+}
+
+impl Kind {
+    /// Whether `value` is of this kind.
+    pub fn check(&self, value: &str) -> bool {
+        self.explain(value).is_none()
+    }
+
+    /// Why `value` isn't of this kind, as what's wrong with it such as `exceeds 76in`, or `None`
+    /// if it is.
+    pub fn explain(&self, value: &str) -> Option<String> {
+        let is_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+        let in_range = |n: u32, range: &RangeInclusive<u32>, unit: &str, under: &str, over: &str| {
+            if n < *range.start() {
+                Some(format!("{} {}{}", under, range.start(), unit))
+            } else if n > *range.end() {
+                Some(format!("{} {}{}", over, range.end(), unit))
+            } else {
+                None
+            }
+        };
+
+        match self {
+            Kind::Year(range) => match value.parse() {
+                Ok(year) if value.len() == 4 && is_digits(value) => {
+                    in_range(year, range, "", "is before", "is after")
+                }
+                _ => Some("is not a four-digit year".to_owned()),
+            },
+            Kind::Measure(units) => {
+                let matching: Vec<_> = units.iter()
+                    .filter_map(|(unit, range)| Some((unit, range, value.strip_suffix(unit.as_str())?)))
+                    .collect();
+                // Units can end with each other, like `m` and `cm`, so every one that fits is
+                // tried, and the value is judged by the one whose range the number is nearest.
+                let distance = |n: u32, range: &RangeInclusive<u32>| {
+                    range.start().saturating_sub(n).max(n.saturating_sub(*range.end()))
+                };
+                let closest = matching.iter()
+                    .filter(|(_, _, amount)| is_digits(amount))
+                    .filter_map(|&(unit, range, amount)| Some((unit, range, amount.parse().ok()?)))
+                    .min_by_key(|&(_, range, n)| distance(n, range));
+                match (closest, matching.iter().max_by_key(|(unit, _, _)| unit.len())) {
+                    (Some((unit, range, n)), _) => in_range(n, range, unit, "is under", "exceeds"),
+                    (None, Some((unit, _, _))) => Some(format!("is not a number of {}", unit)),
+                    (None, None) => {
+                        let units: Vec<_> = units.iter().map(|(unit, _)| unit.as_str()).collect();
+                        Some(format!("has no unit out of {}", units.join(", ")))
+                    }
+                }
+            }
+            Kind::Colour => {
+                let is_hex = |hex: &str| hex.len() == 6 && hex.bytes().all(|b| b.is_ascii_hexdigit());
+                match value.strip_prefix('#') {
+                    Some(hex) if is_hex(hex) => None,
+                    _ => Some("is not `#` followed by six hex digits".to_owned()),
+                }
+            }
+            Kind::OneOf(words) if words.iter().any(|word| word == value) => None,
+            Kind::OneOf(words) => Some(format!("is not one of {}", words.join(", "))),
+            Kind::Digits(len) if value.len() == *len && is_digits(value) => None,
+            Kind::Digits(len) => Some(format!("is not {} digits", len)),
+            Kind::Any => None,
+        }
+    }
+}
+
+impl Schema {
+    /// Reads a schema, reporting the first line that doesn't make sense.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut fields = Vec::new();
+        for (index, line) in input.lines().enumerate() {
+            if line.trim().is_empty() || line.trim_start().starts_with('#') {
+                continue;
+            }
+            fields.push(aoc_nom::finish(line, field_rule).map_err(|e| e.on_line(index + 1))?);
+        }
+        Ok(Schema { fields })
+    }
+
+    /// The passports of the puzzle, as in part 2.
+    pub fn passport() -> Self {
+        Schema::parse(PASSPORT).expect("the passport schema is valid")
+    }
+
+    /// Whether `document` has every required field, whatever their values.
+    pub fn has_required(&self, document: &[(&str, &str)]) -> bool {
+        self.fields.iter()
+            .filter(|rule| rule.required)
+            .all(|rule| document.iter().any(|(name, _)| *name == rule.name))
+    }
+
+    /// Whether `document` has every required field, and every field the schema knows of has a
+    /// value of the right kind.
+    ///
+    /// Fields the schema doesn't mention are left alone.
+    pub fn validate(&self, document: &[(&str, &str)]) -> bool {
+        self.has_required(document)
+            && document.iter().all(|(name, value)| {
+                self.fields.iter()
+                    .filter(|rule| rule.name == *name)
+                    .all(|rule| rule.kind.check(value))
+            })
+    }
+}
+
+/// Reads documents of `name:value` fields, separated by blank lines, as in the puzzle input.
+pub fn parse_documents(input: &str) -> Result<Vec<Document<'_>>, ParseError> {
+    aoc_nom::finish(input, blocks(record(name, field)))
+}
+
+fn name(i: &str) -> IResult<'_, &str> {
+    context("a name", take_while1(|c: char| c.is_alphanumeric() || c == '_' || c == '-'))(i)
+}
+
+fn range(i: &str) -> IResult<'_, RangeInclusive<u32>> {
+    context(
+        "a range such as `1920-2002`",
+        map(separated_pair(unsigned, char('-'), unsigned), |(min, max)| min..=max),
+    )(i)
+}
+
+fn kind(i: &str) -> IResult<'_, Kind> {
+    let year = preceded(tuple((tag("year"), space1)), map(range, Kind::Year));
+    let unit = separated_pair(map(name, str::to_owned), space1, range);
+    let measure = preceded(tuple((tag("measure"), space1)), map(separated_list1(space1, unit), Kind::Measure));
+    let words = separated_list1(space1, map(name, str::to_owned));
+    let one_of = preceded(tuple((tag("one-of"), space1)), map(words, Kind::OneOf));
+    let digits = preceded(tuple((tag("digits"), space1)), map(unsigned, Kind::Digits));
+
+    context(
+        "`year`, `measure`, `colour`, `one-of`, `digits` or `any`",
+        alt((year, measure, value(Kind::Colour, tag("colour")), one_of, digits, value(Kind::Any, tag("any")))),
+    )(i)
+}
+
+fn field_rule(i: &str) -> IResult<'_, FieldRule> {
+    let required = alt((value(true, tag("required")), value(false, tag("optional"))));
+    let (i, (name, _, required, _, kind)) =
+        tuple((name, space1, context("`required` or `optional`", required), space1, kind))(i)?;
+    Ok((i, FieldRule { name: name.to_owned(), required, kind }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_passport() {
+        let schema = Schema::passport();
+        assert_eq!(8, schema.fields.len());
+        assert_eq!(
+            FieldRule {
+                name: "hgt".to_owned(),
+                required: true,
+                kind: Kind::Measure(vec![("cm".to_owned(), 150..=193), ("in".to_owned(), 59..=76)]),
+            },
+            schema.fields[3],
+        );
+        assert!(!schema.fields[7].required);
+    }
+
+    #[test]
+    fn test_check() {
+        let year = Kind::Year(2010..=2020);
+        assert!(year.check("2010"));
+        assert!(!year.check("2021"));
+        assert!(!year.check("+201"));
+
+        let height = Schema::passport().fields[3].kind.clone();
+        assert!(height.check("60in"));
+        assert!(height.check("190cm"));
+        assert!(!height.check("190in"));
+        assert!(!height.check("190"));
+        assert!(!height.check("cm"));
+
+        assert!(Kind::Colour.check("#123abc"));
+        assert!(!Kind::Colour.check("#123abz"));
+        assert!(!Kind::Colour.check("123abc"));
+        assert!(Kind::Digits(9).check("000000001"));
+        assert!(!Kind::Digits(9).check("0123456789"));
+        assert!(Kind::OneOf(vec!["brn".to_owned()]).check("brn"));
+        assert!(!Kind::OneOf(vec!["brn".to_owned()]).check("wat"));
+    }
+
+    #[test]
+    fn test_overlapping_units() {
+        let length = Schema::parse("len required measure m 1-5 cm 100-500").unwrap().fields[0].kind.clone();
+        assert!(length.check("150cm"));
+        assert!(length.check("3m"));
+        assert_eq!(Some("exceeds 500cm".to_owned()), length.explain("600cm"));
+        assert_eq!(Some("exceeds 5m".to_owned()), length.explain("9m"));
+        assert_eq!(Some("is under 100cm".to_owned()), length.explain("0cm"));
+        assert_eq!(Some("is under 1m".to_owned()), length.explain("0m"));
+        assert_eq!(Some("is not a number of cm".to_owned()), length.explain("xcm"));
+        assert_eq!(Some("has no unit out of m, cm".to_owned()), length.explain("150"));
+    }
+
+    #[test]
+    fn test_other_documents() {
+        let schema = Schema::parse("\
+# A library card.
+name required any
+
+expires required year 2020-2099
+colour optional one-of red green
+").unwrap();
+        let documents = parse_documents("name:ada expires:2030\n\nname:bob colour:blue expires:2040\n\nname:cy").unwrap();
+        let valid: Vec<_> = documents.iter().map(|document| schema.validate(document)).collect();
+        assert_eq!(vec![true, false, false], valid);
+        assert!(schema.has_required(&documents[1]));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Schema::parse("byr required year 1920-2002\n\nhgt mandatory any").unwrap_err();
+        assert_eq!(ParseError::new(5, "`required` or `optional`", "mandatory").on_line(3), error);
+
+        let error = Schema::parse("hgt required measure cm tall").unwrap_err();
+        assert_eq!((1, 25, "tall"), (error.line, error.column, &*error.found));
+    }
+}
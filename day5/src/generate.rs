@@ -0,0 +1,39 @@
+use aoc_core::generate::Generated;
+use rand::{seq::SliceRandom, Rng};
+
+use crate::boarding_pass;
+
+/// Number of boarding passes in a generated list.
+pub const DEFAULT_SIZE: usize = 800;
+
+/// Boarding passes for a run of `size + 1` consecutive seats, less one seat somewhere in the
+/// middle of it.
+///
+/// `size` is capped so that the run fits on the plane's 1024 seats.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+    let size = size.clamp(2, 1000);
+    let first = rng.gen_range(1..1024 - size);
+    let last = first + size;
+    let missing = rng.gen_range(first + 1..last);
+
+    let mut seats: Vec<usize> = (first..=last).filter(|&seat| seat != missing).collect();
+    seats.shuffle(rng);
+
+    let input = seats.iter().map(|&seat| format!("{}\n", boarding_pass(seat))).collect();
+    Generated::new(input, Some(last.to_string()), Some(missing.to_string()))
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day5;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            generate(&mut StdRng::seed_from_u64(seed), 100).assert_solves::<Day5>();
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use aoc_core::{ParseError, Solver};
+
+#[cfg(feature = "generate")]
+pub mod generate;
+
+/// Day 5: Binary Boarding
+pub struct Day5;
+
+impl Solver for Day5 {
+    const DAY: u8 = 5;
+
+    type Input<'a> = &'a str;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        get_answer(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        get_answer2(input)
+    }
+}
+
+fn get_answer(input: &str) -> usize {
+    input.lines().map(number_from_binary).max().unwrap()
+}
+
+fn get_answer2(input: &str) -> usize {
+    let mut vec: Vec<usize> = input.lines().map(number_from_binary).collect();
+    vec.sort();
+    for pair in vec.windows(2) {
+        if pair[1] - pair[0] > 1 {
+            return pair[0] + 1;
+        }
+    }
+    panic!("No empty seats")
+}
+
+/// The seat ID of a boarding pass: `F`/`L` are 0 bits and `B`/`R` are 1 bits, most significant first.
+///
+/// Panics on any other byte.
+pub fn number_from_binary(bytes: &(impl AsRef<[u8]> + ?Sized)) -> usize {
+    bytes.as_ref().iter().fold(0, |num, byte| {
+        match byte {
+            b'F' | b'L' => 2 * num,
+            b'B' | b'R' => 2 * num + 1,
+            _ => std::panic::panic_any(*byte)
+        }
+    })
+}
+
+/// The boarding pass for a seat ID, the inverse of [`number_from_binary`].
+pub fn boarding_pass(seat: usize) -> String {
+    (0..10).rev().map(|bit| {
+        let set = seat >> bit & 1 == 1;
+        match (bit >= 3, set) {
+            (true, false) => 'F',
+            (true, true) => 'B',
+            (false, false) => 'L',
+            (false, true) => 'R',
+        }
+    }).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn part1() {
+        assert_eq!(567, number_from_binary("BFFFBBFRRR"));
+        assert_eq!(119, number_from_binary("FFFBBBFRRR"));
+        assert_eq!(820, number_from_binary("BBFFBBFRLL"));
+    }
+
+    #[test]
+    fn test_boarding_pass() {
+        assert_eq!("BFFFBBFRRR", boarding_pass(567));
+    }
+
+    proptest! {
+        #[test]
+        fn number_from_binary_decodes_any_seat(seat in 0..1024usize) {
+            prop_assert_eq!(seat, number_from_binary(&boarding_pass(seat)));
+        }
+
+        #[test]
+        fn boarding_pass_encodes_any_pass(pass in "[FB]{7}[LR]{3}") {
+            prop_assert_eq!(pass.clone(), boarding_pass(number_from_binary(&pass)));
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use aoc_core::generate::Generated;
+use rand::Rng;
+
+/// Number of groups in a generated list of answers.
+pub const DEFAULT_SIZE: usize = 500;
+
+/// Answers for `size` groups of one to five people.
+///
+/// Each group favours a few questions that most of its people answer yes to, so that some
+/// questions are answered by everyone.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+    let (mut anyone, mut everyone) = (0, 0);
+    let mut groups = Vec::with_capacity(size);
+
+    for _ in 0..size {
+        let favourites: Vec<bool> = (0..26).map(|_| rng.gen_bool(0.3)).collect();
+        let people: Vec<Vec<bool>> = (0..rng.gen_range(1..=5))
+            .map(|_| loop {
+                let yes: Vec<bool> = favourites.iter()
+                    .map(|&favourite| rng.gen_bool(if favourite { 0.9 } else { 0.05 }))
+                    .collect();
+                if yes.contains(&true) {
+                    break yes;
+                }
+            })
+            .collect();
+
+        anyone += (0..26).filter(|&q| people.iter().any(|yes| yes[q])).count();
+        everyone += (0..26).filter(|&q| people.iter().all(|yes| yes[q])).count();
+        let lines: Vec<String> = people.iter()
+            .map(|yes| (b'a'..=b'z').zip(yes).filter(|(_, &yes)| yes).map(|(q, _)| q as char).collect())
+            .collect();
+        groups.push(lines.join("\n"));
+    }
+
+    let input = groups.join("\n\n") + "\n";
+    Generated::new(input, Some(anyone.to_string()), Some(everyone.to_string()))
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day6;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            generate(&mut StdRng::seed_from_u64(seed), 50).assert_solves::<Day6>();
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use aoc_core::{ParseError, Solver};
+
+#[cfg(feature = "generate")]
+pub mod generate;
+
+/// Day 6: Custom Customs
+pub struct Day6;
+
+impl Solver for Day6 {
+    const DAY: u8 = 6;
+
+    type Input<'a> = &'a str;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        get_answer(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        get_answer2(input)
+    }
+}
+
+fn get_answer(input: &str) -> usize {
+    input.split("\n\n").map(get_group_count).sum()
+}
+
+/// The number of questions anyone in the group answered yes to.
+pub fn get_group_count(group_answers: &str) -> usize {
+    let questions = "abcdefghijklmnopqrstuvwxyz";
+
+    questions.chars().filter(|c| group_answers.contains(*c)).count()
+}
+
+fn get_answer2(input: &str) -> usize {
+    input.split("\n\n").map(get_group_count2).sum()
+}
+
+/// The number of questions everyone in the group answered yes to.
+pub fn get_group_count2(group_answers: &str) -> usize {
+    let questions = "abcdefghijklmnopqrstuvwxyz";
+    let mut answers: Vec<_> = questions.chars().collect();
+
+    for individual in group_answers.lines() {
+        answers.retain(|c| individual.contains(*c))
+    }
+
+    answers.len()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn part1() {
+        assert_eq!(11, get_answer(GROUP_ANSWERS));
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(6, get_answer2(GROUP_ANSWERS));
+    }
+
+    const GROUP_ANSWERS: &str = "\
+abc
+
+a
+b
+c
+
+ab
+ac
+
+a
+a
+a
+a
+
+b";
+}
\ No newline at end of file
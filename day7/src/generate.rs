@@ -0,0 +1,90 @@
+use aoc_core::generate::Generated;
+use rand::{seq::SliceRandom, Rng};
+
+/// Number of bag colours in a generated set of rules.
+pub const DEFAULT_SIZE: usize = 600;
+
+const SHADES: [&str; 24] = [
+    "bright", "clear", "dark", "dim", "dotted", "drab", "dull", "faded", "light", "mirrored",
+    "muted", "pale", "plaid", "posh", "shiny", "striped", "vibrant", "wavy", "dashed", "matte",
+    "glossy", "frosted", "speckled", "smoky",
+];
+const COLOURS: [&str; 33] = [
+    "aqua", "beige", "black", "blue", "bronze", "brown", "chartreuse", "coral", "crimson", "cyan",
+    "fuchsia", "gold", "gray", "green", "indigo", "lavender", "lime", "magenta", "maroon", "olive",
+    "orange", "plum", "purple", "red", "salmon", "silver", "tan", "teal", "tomato", "turquoise",
+    "violet", "white", "yellow",
+];
+
+/// No bag holds more than this many bags, itself included, which keeps the answers in range and
+/// the number of paths through the rules manageable.
+const MAX_CONTENTS: usize = 50_000;
+
+/// Rules for `size` colours of bag, `shiny gold` among them, that hold each other without any
+/// cycles.
+///
+/// The colours are put in a random order and each may only hold colours that come after it, so
+/// the rules form a DAG.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+    let mut names: Vec<String> = SHADES.iter()
+        .flat_map(|shade| COLOURS.iter().map(move |colour| format!("{} {}", shade, colour)))
+        .filter(|name| name != "shiny gold")
+        .collect();
+    names.shuffle(rng);
+    let n = size.clamp(2, names.len());
+    names.truncate(n - 1);
+    let gold = rng.gen_range(n / 4..=n - 1 - n / 4);
+    names.insert(gold, "shiny gold".to_owned());
+
+    // Each colour's contents and the number of bags a bag of it amounts to, built from the back.
+    let mut contents = vec![Vec::new(); n];
+    let mut total = vec![1; n];
+    for i in (0..n - 1).rev() {
+        let mut later: Vec<usize> = (i + 1..n).collect();
+        later.shuffle(rng);
+        let kinds = if rng.gen_bool(0.2) { 0 } else { rng.gen_range(1..=4) };
+        for &j in later.iter().take(kinds) {
+            let count = rng.gen_range(1..=5);
+            if total[i] + count * total[j] <= MAX_CONTENTS {
+                contents[i].push((count, j));
+                total[i] += count * total[j];
+            }
+        }
+    }
+
+    let mut holds_gold = vec![false; n];
+    for i in (0..n).rev() {
+        holds_gold[i] = contents[i].iter().any(|&(_, j)| j == gold || holds_gold[j]);
+    }
+    let part1 = holds_gold.iter().filter(|&&holds| holds).count();
+    let part2 = total[gold] - 1;
+
+    let mut rules: Vec<String> = names.iter().zip(&contents).map(|(name, contents)| {
+        if contents.is_empty() {
+            return format!("{} bags contain no other bags.", name);
+        }
+        let contents: Vec<String> = contents.iter()
+            .map(|&(count, j)| format!("{} {} bag{}", count, names[j], if count == 1 { "" } else { "s" }))
+            .collect();
+        format!("{} bags contain {}.", name, contents.join(", "))
+    }).collect();
+    rules.shuffle(rng);
+
+    let input = rules.iter().map(|rule| format!("{}\n", rule)).collect();
+    Generated::new(input, Some(part1.to_string()), Some(part2.to_string()))
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day7;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            generate(&mut StdRng::seed_from_u64(seed), 100).assert_solves::<Day7>();
+        }
+    }
+}
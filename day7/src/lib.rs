@@ -0,0 +1,190 @@
+use aoc_core::{parse, ParseError, Solver};
+use petgraph::{algo, graphmap::DiGraphMap};
+
+#[cfg(feature = "generate")]
+pub mod generate;
+
+/// Day 7: Handy Haversacks
+pub struct Day7;
+
+impl Solver for Day7 {
+    const DAY: u8 = 7;
+
+    type Input<'a> = Vec<Rule<'a>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        process_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        get_answer(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        get_answer2(input)
+    }
+}
+
+fn process_input(input: &str) -> Result<Vec<Rule<'_>>, ParseError> {
+    parse::lines(input, Rule::parse)
+}
+
+fn get_answer(rules: &[Rule<'_>]) -> usize {
+    let mut graph = RuleGraph::new();
+    graph.add_rules(rules);
+    graph.count_parents("shiny gold") - 1
+}
+
+fn get_answer2(rules: &[Rule<'_>]) -> usize {
+    let mut graph = RuleGraph::new();
+    graph.add_rules(rules);
+    graph.count_contents("shiny gold") - 1
+}
+
+/// What one colour of bag must hold, e.g. `light red bags contain 1 bright white bag.`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule<'a> {
+    pub container: &'a str,
+    /// How many of each colour of bag go inside.
+    pub contents: Vec<(usize, String)>
+}
+
+impl<'r> Rule<'r> {
+    pub fn parse(s: &'r str) -> Result<Self, ParseError> {
+        let (container, contents_str) = s.split_once(" bags contain ")
+            .ok_or_else(|| ParseError::at(s, s, "`<colour> bags contain <contents>`"))?;
+
+        let contents = if contents_str == "no other bags." {
+            Vec::new()
+        } else {
+            let list = contents_str.strip_suffix('.')
+                .ok_or_else(|| ParseError::at(s, &s[s.len()..], "`.`"))?;
+            list.split(", ")
+                .map(|content| {
+                    let mut words = content.split_whitespace();
+                    let num = parse::number(s, words.next().unwrap_or(content))?;
+                    let descriptor1 = words.next().unwrap_or(&content[content.len()..]);
+                    let descriptor2 = words.next().unwrap_or(&content[content.len()..]);
+                    match words.next() {
+                        Some("bag") | Some("bags") => {}
+                        other => {
+                            let found = other.unwrap_or(&content[content.len()..]);
+                            return Err(ParseError::at(s, found, "`bag` or `bags`"));
+                        }
+                    }
+                    let bag = format!("{} {}", descriptor1, descriptor2);
+
+                    Ok((num, bag))
+                })
+                .collect::<Result<_, _>>()?
+        };
+
+        Ok(Rule {
+            container,
+            contents,
+        })
+    }
+}
+
+/// The bag rules as a graph, with an edge from each bag to the bags it holds.
+#[derive(Default)]
+pub struct RuleGraph<'g> {
+    graph: DiGraphMap<&'g str, usize>
+}
+
+impl<'g> RuleGraph<'g> {
+    pub fn new() -> Self {
+        RuleGraph {
+            graph: DiGraphMap::new(),
+        }
+    }
+
+    pub fn add_rules<'r: 'g, 's: 'g>(&mut self, rules: &'s [Rule<'r>]) {
+        for rule in rules {
+            self.graph.add_node(rule.container);
+        }
+        for rule in rules {
+            for (weight, content) in rule.contents.iter() {
+                self.graph.add_edge(rule.container, content, *weight);
+            }
+        }
+    }
+
+    /// The number of bags that can eventually hold a `name` bag, counting `name` itself.
+    pub fn count_parents(&self, name: &str) -> usize {
+        self.graph.nodes()
+            .filter(|n| algo::has_path_connecting(&self.graph, n, name, None)).count()
+    }
+
+    /// The number of bags inside a `name` bag, counting the `name` bag itself.
+    pub fn count_contents(&self, name: &str) -> usize {
+        let mut count = 1;
+        for (_, neighbor, edge) in self.graph.edges(name) {
+            count += edge * self.count_contents(neighbor);
+        }
+        count
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rule_parse() {
+        let text = "light red bags contain 1 bright white bag, 2 muted yellow bags.";
+        let rule = Rule {
+            container: "light red",
+            contents: vec![
+                (1, "bright white".to_string()),
+                (2, "muted yellow".to_string()),
+            ]
+        };
+
+        assert_eq!(Ok(rule), Rule::parse(text));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = process_input("light red bags hold 1 bright white bag.").err();
+        assert_eq!(Some(1), error.map(|e| e.column));
+
+        let error = process_input("light red bags contain one bright white bag.").err();
+        assert_eq!(Some(ParseError::new(24, "a number", "one")), error);
+
+        let error = process_input("faded blue bags contain no other bags.\nlight red bags contain 1 bright white box.").err();
+        assert_eq!(Some(ParseError::new(39, "`bag` or `bags`", "box").on_line(2)), error);
+    }
+
+    #[test]
+    fn test_count_parents() {
+        let input = "\
+light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.";
+
+        assert_eq!(4, get_answer(&process_input(input).unwrap()))
+    }
+
+    #[test]
+    fn test_count_contents() {
+        let input = "\
+shiny gold bags contain 2 dark red bags.
+dark red bags contain 2 dark orange bags.
+dark orange bags contain 2 dark yellow bags.
+dark yellow bags contain 2 dark green bags.
+dark green bags contain 2 dark blue bags.
+dark blue bags contain 2 dark violet bags.
+dark violet bags contain no other bags.";
+
+        assert_eq!(126, get_answer2(&process_input(input).unwrap()));
+    }
+}
@@ -0,0 +1,98 @@
+use aoc_core::generate::Generated;
+use rand::Rng;
+
+/// Number of instructions in a generated program.
+pub const DEFAULT_SIZE: usize = 600;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Op {
+    Acc,
+    Jmp,
+    Nop,
+}
+
+/// A program of `size` instructions that loops forever, but would run off its end if one `jmp`
+/// on its path were a `nop`.
+///
+/// The program starts out terminating, with only forward jumps, then one `nop` that it runs is
+/// turned into a `jmp` back to somewhere it has already been. `size` is capped at 1000 so the
+/// accumulator can't overflow.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+    let n = size.clamp(2, 1000) as i16;
+    let mut code: Vec<(Op, i16)> = (0..n).map(|i| match rng.gen_range(0..10) {
+        0..=4 => (Op::Acc, rng.gen_range(-15..=15)),
+        5..=6 => (Op::Nop, rng.gen_range(-n..=n)),
+        _ => (Op::Jmp, rng.gen_range(1..=10.min(n - i))),
+    }).collect();
+
+    let path = loop {
+        let (_, path) = run(&code);
+        match path.iter().filter(|&&i| code[i].0 == Op::Nop).count() {
+            0 => code[path[rng.gen_range(0..path.len())]] = (Op::Nop, 0),
+            _ => break path,
+        }
+    };
+    let nops: Vec<usize> = (0..path.len()).filter(|&step| code[path[step]].0 == Op::Nop).collect();
+    let step = nops[rng.gen_range(0..nops.len())];
+    let target = path[rng.gen_range(0..=step)];
+    code[path[step]] = (Op::Jmp, target as i16 - path[step] as i16);
+
+    let (part1, _) = run(&code);
+    let part2 = (0..code.len()).find_map(|i| {
+        let mut fixed = code.clone();
+        fixed[i].0 = match fixed[i].0 {
+            Op::Acc => return None,
+            Op::Jmp => Op::Nop,
+            Op::Nop => Op::Jmp,
+        };
+        match run(&fixed) {
+            (acc, path) if path.last().map(|&last| next(&fixed, last)) == Some(n) => Some(acc),
+            _ => None,
+        }
+    });
+
+    let input = code.iter().map(|&(op, arg)| {
+        let name = match op { Op::Acc => "acc", Op::Jmp => "jmp", Op::Nop => "nop" };
+        format!("{} {:+}\n", name, arg)
+    }).collect();
+    Generated::new(input, Some(part1.to_string()), part2.map(|acc| acc.to_string()))
+}
+
+/// The index run after instruction `i`.
+fn next(code: &[(Op, i16)], i: usize) -> i16 {
+    match code[i] {
+        (Op::Jmp, arg) => i as i16 + arg,
+        _ => i as i16 + 1,
+    }
+}
+
+/// Runs `code` until it leaves the program or comes back to an instruction, returning the
+/// accumulator and the indexes it ran in order.
+fn run(code: &[(Op, i16)]) -> (i16, Vec<usize>) {
+    let mut seen = vec![false; code.len()];
+    let (mut acc, mut i, mut path) = (0, 0, Vec::new());
+    while (0..code.len() as i16).contains(&i) && !seen[i as usize] {
+        seen[i as usize] = true;
+        path.push(i as usize);
+        if let (Op::Acc, arg) = code[i as usize] {
+            acc += arg;
+        }
+        i = next(code, i as usize);
+    }
+    (acc, path)
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day8;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            generate(&mut StdRng::seed_from_u64(seed), 100).assert_solves::<Day8>();
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use std::fmt;
+use std::str::FromStr;
+
+use aoc_core::{parse, ParseError, Solver};
+
+#[cfg(feature = "generate")]
+pub mod generate;
+#[cfg(feature = "repl")]
+pub mod repl;
+
+/// Day 8: Handheld Halting
+pub struct Day8;
+
+impl Solver for Day8 {
+    const DAY: u8 = 8;
+
+    type Input<'a> = Vec<Instruction>;
+    type Answer1 = i16;
+    type Answer2 = i16;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        process_input(input)
+    }
+
+    fn part1(code: &Self::Input<'_>) -> i16 {
+        get_answer(code)
+    }
+
+    fn part2(code: &Self::Input<'_>) -> i16 {
+        get_answer2(&mut code.clone())
+    }
+}
+
+fn process_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse::lines(input, Instruction::from_str)
+}
+
+fn get_answer(code: &[Instruction]) -> i16 {
+    let mut machine = Machine::default();
+    machine.run(code);
+    machine.accumulator
+}
+
+fn get_answer2(code: &mut [Instruction]) -> i16 {
+    let mut machine = Machine::default();
+
+    for changed_index in 0..code.len() {
+        code[changed_index].flip();
+
+        if machine.run(code).terminates() {break;}
+        machine.reset();
+
+        code[changed_index].flip();
+    }
+
+    machine.accumulator
+}
+
+/// One line of boot code.
+#[derive(Copy, Clone, Debug)]
+pub enum Instruction {
+    Acc(i16),
+    Jmp(i16),
+    Nop(i16),
+}
+
+/// Parses a line such as `jmp -3`.
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = s.split_whitespace();
+        let name = words.next().unwrap_or("");
+        let value = words.next().unwrap_or("");
+        if let Some(extra) = words.next() {
+            return Err(ParseError::at(s, extra, "end of line"));
+        }
+
+        let instruction = match name {
+            "acc" => Instruction::Acc,
+            "jmp" => Instruction::Jmp,
+            "nop" => Instruction::Nop,
+            _ => return Err(ParseError::at(s, name, "`acc`, `jmp` or `nop`")),
+        };
+        Ok(instruction(parse::number(s, value)?))
+    }
+}
+
+/// Writes the instruction as it appears in the boot code, e.g. `jmp -3`.
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Instruction::Acc(num) => write!(f, "acc {:+}", num),
+            Instruction::Jmp(num) => write!(f, "jmp {:+}", num),
+            Instruction::Nop(num) => write!(f, "nop {:+}", num),
+        }
+    }
+}
+
+impl Instruction {
+    /// Swaps `jmp` for `nop` and back, leaving `acc` alone.
+    pub fn flip(&mut self) {
+        match self {
+            Instruction::Acc(_) => {},
+            Instruction::Jmp(num) => {*self = Instruction::Nop(*num)}
+            Instruction::Nop(num) => {*self = Instruction::Jmp(*num)}
+        }
+    }
+}
+
+/// The handheld console running the boot code.
+#[derive(Debug, Default)]
+pub struct Machine {
+    /// The index of the next instruction to run.
+    pub index: i16,
+    pub accumulator: i16,
+}
+
+impl Machine {
+    /// The next instruction and its index, or how the program ended if there isn't one.
+    pub fn get_instruction(&self, code:&[Instruction]) -> Result<(usize, Instruction), ExitCode> {
+        if self.index < 0 {
+            return Err(ExitCode::OutOfBounds(self.index));
+        }
+        let index = self.index as usize;
+
+        if index < code.len() {
+            Ok((index, code[index]))
+        } else if index == code.len() {
+            Err(ExitCode::Terminates)
+        } else {
+            Err(ExitCode::OutOfBounds(self.index))
+        }
+
+    }
+
+    /// Runs one instruction and moves on to the next.
+    pub fn execute(&mut self, instruction: Instruction) {
+        match instruction {
+            Instruction::Acc(num) => {self.accumulator += num},
+            Instruction::Jmp(num) => {self.index += num - 1},
+            Instruction::Nop(_) => {}
+        }
+        self.index += 1;
+    }
+
+    /// Runs `code` until it ends or is about to run an instruction for the second time.
+    pub fn run(&mut self, code: &[Instruction]) -> ExitCode {
+        let mut visited = vec![false; code.len()];
+
+        loop {
+            match self.get_instruction(code) {
+                Ok((index, instruction)) => {
+                    if visited[index] {return ExitCode::EndlessLoop;}
+                    visited[index] = true;
+        
+                    self.execute(instruction);
+                },
+                Err(code) => break code,
+            };
+        }
+    }
+
+    pub fn reset(&mut self) {
+        *self = Machine::default();
+    }
+}
+
+/// How a run of the boot code stopped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExitCode {
+    /// It ran off the end of the program, just past the last instruction.
+    Terminates,
+    EndlessLoop,
+    /// It jumped somewhere other than the end of the program.
+    OutOfBounds(i16),
+}
+
+impl ExitCode {
+    pub fn terminates(self) -> bool {
+        ExitCode::Terminates == self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "\
+nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6";
+
+    #[test]
+    fn test_answer() {
+        let code = process_input(INPUT).unwrap();
+
+        assert_eq!(5, get_answer(&code));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = process_input("nop +0\nacc +1\njump +4").unwrap_err();
+        assert_eq!(ParseError::new(1, "`acc`, `jmp` or `nop`", "jump").on_line(3), error);
+
+        let error = process_input("acc 1x").unwrap_err();
+        assert_eq!(ParseError::new(5, "a number", "1x"), error);
+    }
+}
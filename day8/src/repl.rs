@@ -0,0 +1,111 @@
+use aoc_core::repl::{self, Command, Explore, Session};
+
+use crate::{Day8, ExitCode, Instruction, Machine};
+
+const COMMANDS: &[Command] = &[
+    Command { name: "step", args: "[n]", help: "Run the next n instructions, or 1, showing each" },
+    Command { name: "run", args: "", help: "Run until the program ends or is about to repeat an instruction" },
+    Command { name: "show", args: "", help: "Show the accumulator and the next instruction" },
+    Command { name: "flip", args: "<index>", help: "Swap the `jmp` or `nop` at index for the other" },
+    Command { name: "reset", args: "", help: "Start again from the first instruction" },
+];
+
+/// The console partway through the boot code, remembering which instructions it's run.
+struct Console {
+    code: Vec<Instruction>,
+    machine: Machine,
+    visited: Vec<bool>,
+}
+
+impl Explore for Day8 {
+    fn explore<'a>(code: Self::Input<'a>) -> Box<dyn Session + 'a> {
+        let visited = vec![false; code.len()];
+        Box::new(Console { code, machine: Machine::default(), visited })
+    }
+}
+
+impl Session for Console {
+    fn commands(&self) -> &'static [Command] {
+        COMMANDS
+    }
+
+    fn run(&mut self, command: &str, args: &str) -> Result<String, String> {
+        match command {
+            "step" => {
+                let mut printed = String::new();
+                for _ in 0..repl::count(args)? {
+                    match self.machine.get_instruction(&self.code) {
+                        Ok((index, instruction)) => {
+                            let again = if self.visited[index] { " (again)" } else { "" };
+                            self.visited[index] = true;
+                            self.machine.execute(instruction);
+                            let instruction = instruction.to_string();
+                            printed += &format!("{:>4}: {:<8} acc {}{}\n", index, instruction, self.machine.accumulator, again);
+                        }
+                        Err(exit) => {
+                            printed += &describe(exit);
+                            break;
+                        }
+                    }
+                }
+                Ok(printed)
+            }
+            "run" => loop {
+                match self.machine.get_instruction(&self.code) {
+                    Ok((index, _)) if self.visited[index] => {
+                        break Ok(format!("about to run {} again, with acc {}", index, self.machine.accumulator));
+                    }
+                    Ok((index, instruction)) => {
+                        self.visited[index] = true;
+                        self.machine.execute(instruction);
+                    }
+                    Err(exit) => break Ok(format!("{}, with acc {}", describe(exit), self.machine.accumulator)),
+                }
+            },
+            "show" => Ok(match self.machine.get_instruction(&self.code) {
+                Ok((index, instruction)) => format!("acc {}, next {}: {}", self.machine.accumulator, index, instruction),
+                Err(exit) => format!("acc {}, {}", self.machine.accumulator, describe(exit)),
+            }),
+            "flip" => {
+                let index: usize = args.parse().map_err(|_| format!("expected an instruction's index, not `{}`", args))?;
+                let len = self.code.len();
+                let instruction = self.code.get_mut(index).ok_or_else(|| format!("there are only {} instructions", len))?;
+                instruction.flip();
+                Ok(format!("{}: {}", index, instruction))
+            }
+            "reset" => {
+                self.machine.reset();
+                self.visited.iter_mut().for_each(|visited| *visited = false);
+                Ok(String::new())
+            }
+            _ => unreachable!("not one of the commands"),
+        }
+    }
+}
+
+fn describe(exit: ExitCode) -> String {
+    match exit {
+        ExitCode::Terminates => "the program has ended".to_owned(),
+        ExitCode::EndlessLoop => "the program loops forever".to_owned(),
+        ExitCode::OutOfBounds(index) => format!("jumped out of the program, to {}", index),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::process_input;
+
+    #[test]
+    fn test_session() {
+        let code = process_input("nop +0\nacc +1\njmp -2").unwrap();
+        let mut console = Day8::explore(code);
+        assert_eq!(Ok("   0: nop +0   acc 0\n   1: acc +1   acc 1\n".to_owned()), console.run("step", "2"));
+        assert_eq!(Ok("about to run 0 again, with acc 1".to_owned()), console.run("run", ""));
+
+        console.run("reset", "").unwrap();
+        assert_eq!(Ok("2: nop -2".to_owned()), console.run("flip", "2"));
+        assert_eq!(Ok("the program has ended, with acc 1".to_owned()), console.run("run", ""));
+        assert!(console.run("flip", "3").is_err());
+    }
+}
@@ -0,0 +1,73 @@
+use aoc_core::generate::Generated;
+use rand::{seq::SliceRandom, Rng};
+
+/// Number of numbers in a generated XMAS stream.
+pub const DEFAULT_SIZE: usize = 1000;
+
+const PREAMBLE: usize = 25;
+
+/// An XMAS stream of `size` numbers in which every number after the preamble is the sum of two
+/// of the 25 before it, except for one planted number that is the sum of a contiguous run of
+/// earlier numbers instead.
+///
+/// The numbers grow as the stream goes on, so `size` is capped at 1000 to keep them in range.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+    let n = size.clamp(PREAMBLE + 10, 1000);
+    let weak = rng.gen_range(n / 2..n);
+
+    let mut numbers: Vec<i64> = (1..=50).collect();
+    numbers.shuffle(rng);
+    numbers.truncate(PREAMBLE);
+    for i in PREAMBLE..n {
+        let window = &numbers[i - PREAMBLE..i];
+        let number = if i == weak {
+            loop {
+                let start = rng.gen_range(i - 50..i - 2);
+                let end = rng.gen_range(start + 2..=i.min(start + 17));
+                let sum = numbers[start..end].iter().sum();
+                if !is_sum(sum, window) && !numbers.contains(&sum) {
+                    break sum;
+                }
+            }
+        } else {
+            // Summing the smaller numbers keeps the stream from growing much faster than doubling
+            // every preamble's length.
+            let mut smallest = window.to_vec();
+            smallest.sort();
+            let mut picks = smallest[..6].choose_multiple(rng, 2);
+            picks.next().unwrap() + picks.next().unwrap()
+        };
+        numbers.push(number);
+    }
+
+    let key = numbers[weak];
+    let part2 = (0..weak).find_map(|start| {
+        (start + 2..=weak).find(|&end| numbers[start..end].iter().sum::<i64>() == key).map(|end| {
+            let run = &numbers[start..end];
+            run.iter().min().unwrap() + run.iter().max().unwrap()
+        })
+    });
+
+    let input = numbers.iter().map(|number| format!("{}\n", number)).collect();
+    Generated::new(input, Some(key.to_string()), part2.map(|sum| sum.to_string()))
+}
+
+/// Whether two different entries of `window` add up to `number`.
+fn is_sum(number: i64, window: &[i64]) -> bool {
+    (0..window.len()).any(|i| (i + 1..window.len()).any(|j| window[i] + window[j] == number))
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day9;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            generate(&mut StdRng::seed_from_u64(seed), 200).assert_solves::<Day9>();
+        }
+    }
+}
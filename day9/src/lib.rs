@@ -0,0 +1,77 @@
+use std::cmp::Ordering;
+
+use aoc_core::{parse, ParseError, Solver};
+
+#[cfg(feature = "generate")]
+pub mod generate;
+
+/// Day 9: Encoding Error
+pub struct Day9;
+
+impl Solver for Day9 {
+    const DAY: u8 = 9;
+
+    type Input<'a> = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        process_input(input)
+    }
+
+    fn part1(ints: &Self::Input<'_>) -> i64 {
+        get_answer(ints)
+    }
+
+    fn part2(ints: &Self::Input<'_>) -> i64 {
+        get_answer2(get_answer(ints), ints)
+    }
+}
+
+fn process_input(input: &str) -> Result<Vec<i64>, ParseError> {
+    parse::lines(input, |s| parse::number(s, s))
+}
+
+fn get_answer(ints: &[i64]) -> i64 {
+    for index in 25..ints.len() {
+        if !check_int(ints[index], &ints[index-25..index]) {
+            return ints[index];
+        }
+    }
+    panic!()
+}
+
+/// Whether two different numbers in `slice` add up to `int`.
+pub fn check_int(int: i64, slice: &[i64]) -> bool{
+    let mut array = slice.iter().collect::<Vec<&i64>>();
+    array.sort();
+
+    let mut head = 0;
+    let mut foot = array.len() - 1;
+
+    while head < foot {
+        let sum = array[head] + array[foot];
+        match sum.cmp(&int) {
+            Ordering::Less => {head +=1; continue;}
+            Ordering::Greater => {foot -=1; continue;}
+            Ordering::Equal => {return true;}
+        }
+    }
+
+    false
+}
+
+fn get_answer2(key: i64, ints: &[i64]) -> i64 {
+    let mut head = 0;
+    let mut foot = 2;
+
+    loop {
+        match ints[head..foot].iter().sum::<i64>().cmp(&key) {
+            Ordering::Less => {foot += 1; continue;}
+            Ordering::Greater => {head +=1; continue;}
+            Ordering::Equal => {break;}
+        }
+    }
+
+    ints[head..foot].iter().min().unwrap() + ints[head..foot].iter().max().unwrap() 
+}
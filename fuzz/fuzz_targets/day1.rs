@@ -0,0 +1,7 @@
+#![no_main]
+use aoc_core::Solver;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = day1::Day1::parse(input);
+});